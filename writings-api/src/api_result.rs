@@ -6,14 +6,24 @@ pub type WritingsApiResult<T> = Result<T, WritingsApiError>;
 #[derive(thiserror::Error, Debug, Display)]
 pub enum WritingsApiError {
     NotFound,
+    BadRequest(String),
+    /// Not ready yet, e.g. while the search index is loading.
+    Unavailable(String),
     Axum(#[from] axum::Error),
     Io(#[from] std::io::Error),
+    Json(#[from] serde_json::Error),
 }
 
 impl axum::response::IntoResponse for WritingsApiError {
     fn into_response(self) -> axum::response::Response {
         match self {
             WritingsApiError::NotFound => StatusCode::NOT_FOUND.into_response(),
+            WritingsApiError::BadRequest(message) => {
+                (StatusCode::BAD_REQUEST, message).into_response()
+            }
+            WritingsApiError::Unavailable(message) => {
+                (StatusCode::SERVICE_UNAVAILABLE, message).into_response()
+            }
             _ => (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error").into_response(),
         }
     }
@@ -1,16 +1,57 @@
-use axum::{Json, extract::Path};
-use utoipa::OpenApi as DeriveOpenApi;
+use axum::{
+    Json,
+    extract::{Path, Query},
+};
+use axum_valid::Validated;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi as DeriveOpenApi, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
-use writings::{EmbedAllTrait as _, Writings, WritingsTrait as _};
+use validify::Validify;
+use writings::{EmbedAllTrait as _, Writings, WritingsTrait as _, search::RelatedResult};
 
-use crate::{WritingsApiError, WritingsApiResult, api_tag};
+use crate::{WritingsApiError, WritingsApiResult, api_tag, search::SearchState};
 
 #[derive(DeriveOpenApi)]
-#[openapi(components(schemas(Writings)))]
+#[openapi(components(schemas(Writings, ByRefResponse, RelatedResult)))]
 pub struct ByRefApiDoc;
 
 pub fn router() -> OpenApiRouter {
-    OpenApiRouter::with_openapi(ByRefApiDoc::openapi()).routes(routes!(by_ref))
+    OpenApiRouter::with_openapi(ByRefApiDoc::openapi())
+        .routes(routes!(by_ref))
+        .routes(routes!(related))
+}
+
+const MAX_CONTEXT: usize = 20;
+const DEFAULT_RELATED: usize = 9;
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct ByRefQuery {
+    /// Include the `ref_id`s of the previous and next paragraphs within the same Work.
+    #[serde(default)]
+    #[param(default = false)]
+    pub nav: bool,
+    /// Include up to this many paragraphs before and after, within the same Work.
+    #[validate(range(min = 0.0, max = 20.0))]
+    #[serde(default)]
+    #[param(default = 0, minimum = 0, maximum = 20)]
+    pub context: usize,
+}
+
+/// The requested Writings, optionally with its neighbours in document order.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ByRefResponse {
+    #[serde(flatten)]
+    pub item: Writings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev_ref_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_ref_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub before: Vec<Writings>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub after: Vec<Writings>,
 }
 
 #[utoipa::path(
@@ -18,22 +59,91 @@ pub fn router() -> OpenApiRouter {
     path = "/{ref_id}",
     tag = api_tag(),
     params(
-        ("ref_id" = String, Path, example = "646181142")
+        ("ref_id" = String, Path, example = "646181142"),
+        ByRefQuery,
     ),
     responses(
-        (status = OK, body = Writings, description = "Writings by ref_id"),
+        (status = OK, body = ByRefResponse, description = "Writings by ref_id"),
+        (status = NOT_FOUND, description = "Writings not found"),
         (status = BAD_REQUEST, description = "bad request / invalid parameters")
     )
 )]
 pub async fn by_ref(
     // MUST be a tuple or it doesn't make it into spec.
     Path((ref_id,)): Path<(String,)>,
-) -> WritingsApiResult<Json<Writings>> {
-    Ok(Json(
-        Writings::all()
-            .iter()
-            .find(|w| w.ref_id() == ref_id)
-            .cloned()
-            .ok_or(WritingsApiError::NotFound)?,
-    ))
+    Validated(Query(query)): Validated<Query<ByRefQuery>>,
+) -> WritingsApiResult<Json<ByRefResponse>> {
+    // The first record with the `ref_id`, as records may share one.
+    let position = *Writings::all_index()
+        .get(&ref_id)
+        .ok_or(WritingsApiError::NotFound)?;
+    let item = Writings::all()[position].clone();
+
+    let (prev_ref_id, next_ref_id) = match query.nav {
+        true => (
+            item.prev().map(|w| w.ref_id()),
+            item.next().map(|w| w.ref_id()),
+        ),
+        false => (None, None),
+    };
+
+    let mut before = item.context(query.context.min(MAX_CONTEXT));
+    let after = match before.iter().position(|w| *w == item) {
+        Some(position) => before.split_off(position).split_off(1),
+        None => vec![],
+    };
+
+    Ok(Json(ByRefResponse {
+        item,
+        prev_ref_id,
+        next_ref_id,
+        before,
+        after,
+    }))
+}
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct RelatedQuery {
+    #[validate(range(min = 1.0, max = 95.0))]
+    #[serde(default = "default_related")]
+    #[param(default = default_related, maximum = 95, minimum = 1)]
+    pub limit: usize,
+    /// Include other paragraphs of the same selection (prayer, Gleaning, etc.).
+    #[serde(default)]
+    #[param(default = false)]
+    pub siblings: bool,
+}
+
+fn default_related() -> usize {
+    DEFAULT_RELATED
+}
+
+/// "More like this": the paragraphs most similar in wording to this one, best first.
+#[utoipa::path(
+    get,
+    path = "/{ref_id}/related",
+    tag = api_tag(),
+    params(
+        ("ref_id" = String, Path, example = "646181142"),
+        RelatedQuery,
+    ),
+    responses(
+        (status = OK, body = Vec<RelatedResult>, description = "Related Writings"),
+        (status = NOT_FOUND, description = "Writings not found"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters"),
+        (status = SERVICE_UNAVAILABLE, description = "search or related index is loading")
+    )
+)]
+pub async fn related(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((ref_id,)): Path<(String,)>,
+    Validated(Query(query)): Validated<Query<RelatedQuery>>,
+) -> WritingsApiResult<Json<Vec<RelatedResult>>> {
+    let state = SearchState::get();
+    let related = state
+        .related()?
+        .related(&ref_id, query.limit, query.siblings)
+        .ok_or(WritingsApiError::NotFound)?;
+    Ok(Json(related))
 }
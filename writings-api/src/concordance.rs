@@ -0,0 +1,135 @@
+use axum::{
+    Json,
+    extract::{Path, Query},
+};
+use axum_valid::Validated;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi as DeriveOpenApi, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use validify::Validify;
+use writings::{
+    Author, EmbedAllTrait as _, Query as WritingsQuery, Work, Writings,
+    search::{Kwic, SearchFacets},
+};
+
+use crate::{WritingsApiResult, api_tag, pagination::Pagination, search::SearchState};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(Kwic, ConcordanceResponse)))]
+pub struct ConcordanceApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(ConcordanceApiDoc::openapi()).routes(routes!(get_concordance))
+}
+
+const DEFAULT_LIMIT: usize = 100;
+const DEFAULT_CONTEXT: usize = 5;
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct ConcordanceQuery {
+    /// Also list the other forms of the word, e.g. "detached" for "detachment".
+    #[serde(default)]
+    #[param(default = false)]
+    pub lemma: bool,
+    pub author: Option<Author>,
+    pub work: Option<Work>,
+    /// The number of words of context on either side.
+    #[validate(range(min = 0.0, max = 20.0))]
+    #[serde(default = "default_context")]
+    #[param(default = default_context, minimum = 0, maximum = 20)]
+    pub context: usize,
+    #[validate(range(min = 1.0, max = 1000.0))]
+    #[serde(default = "default_limit")]
+    #[param(default = default_limit, maximum = 1000, minimum = 1)]
+    pub limit: usize,
+    #[serde(default)]
+    #[param(default = 0)]
+    pub offset: usize,
+}
+
+fn default_context() -> usize {
+    DEFAULT_CONTEXT
+}
+
+fn default_limit() -> usize {
+    DEFAULT_LIMIT
+}
+
+/// A page of the occurrences of a word, with counts of all of them.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConcordanceResponse {
+    #[serde(flatten)]
+    pub pagination: Pagination,
+    /// With `lemma`, the forms of the word listed
+    /// (folded: lowercase, without diacritics or apostrophes).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub forms: Vec<String>,
+    /// Counts of all occurrences by type, author, prayer kind and Work.
+    pub facets: SearchFacets,
+    /// The occurrences in document order, in context.
+    pub lines: Vec<Kwic>,
+}
+
+/// Every occurrence of a word (not ranked or fuzzy) in context, in document order,
+/// ignoring case, diacritics, apostrophes and a possessive "’s".
+#[utoipa::path(
+    get,
+    path = "/{word}",
+    tag = api_tag(),
+    params(
+        ("word" = String, Path, example = "detachment"),
+        ConcordanceQuery,
+    ),
+    responses(
+        (status = OK, body = ConcordanceResponse, description = "Concordance of the word"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters"),
+        (status = SERVICE_UNAVAILABLE, description = "search index or concordance is loading")
+    )
+)]
+pub async fn get_concordance(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((word,)): Path<(String,)>,
+    Validated(Query(query)): Validated<Query<ConcordanceQuery>>,
+) -> WritingsApiResult<Json<ConcordanceResponse>> {
+    let state = SearchState::get();
+    let concordance = state.concordance()?;
+
+    let mut filter = WritingsQuery::new();
+    if let Some(author) = query.author {
+        filter = filter.author(author);
+    }
+    if let Some(work) = query.work {
+        filter = filter.work(work);
+    }
+    let all = Writings::all();
+    let occurrences = concordance
+        .occurrences(&word, query.lemma)
+        .into_iter()
+        .filter(|o| filter.matches(&all[o.position]))
+        .collect::<Vec<_>>();
+
+    let forms = match query.lemma {
+        true => concordance.forms(&word).to_vec(),
+        false => vec![],
+    };
+    let facets = SearchFacets::count(occurrences.iter().map(|o| &all[o.position]));
+    let lines = occurrences
+        .iter()
+        .skip(query.offset)
+        .take(query.limit)
+        .map(|o| o.kwic(query.context))
+        .collect();
+
+    Ok(Json(ConcordanceResponse {
+        pagination: Pagination {
+            limit: query.limit,
+            offset: query.offset,
+            total: occurrences.len(),
+        },
+        forms,
+        facets,
+        lines,
+    }))
+}
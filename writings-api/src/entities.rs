@@ -0,0 +1,148 @@
+use axum::{
+    Json,
+    extract::{Path, Query},
+};
+use axum_valid::Validated;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi as DeriveOpenApi, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use validify::Validify;
+use writings::{Entity, EntityKind, Mention, Work};
+
+use crate::{WritingsApiError, WritingsApiResult, api_tag, pagination::Pagination};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(Entity, EntityKind, Mention, MentionsResponse)))]
+pub struct EntitiesApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(EntitiesApiDoc::openapi())
+        .routes(routes!(entities_all))
+        .routes(routes!(entity))
+        .routes(routes!(entity_mentions))
+}
+
+const DEFAULT_LIMIT: usize = 100;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EntitiesQuery {
+    /// Only list persons or places.
+    pub kind: Option<EntityKind>,
+}
+
+/// The persons and places of the gazetteer, with their variant spellings.
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = api_tag(),
+    params(EntitiesQuery),
+    responses(
+        (status = OK, body = Vec<Entity>, description = "The gazetteer"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn entities_all(
+    Query(query): Query<EntitiesQuery>,
+) -> WritingsApiResult<Json<Vec<Entity>>> {
+    Ok(Json(
+        Entity::all()
+            .iter()
+            .filter(|entity| query.kind.is_none_or(|kind| entity.kind == kind))
+            .cloned()
+            .collect(),
+    ))
+}
+
+/// A person or place, by its `id` or any name, ignoring case and diacritics.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = api_tag(),
+    params(("id" = String, Path, example = "carmel")),
+    responses(
+        (status = OK, body = Entity, description = "The person or place"),
+        (status = NOT_FOUND, description = "Entity not found"),
+    )
+)]
+pub async fn entity(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((id,)): Path<(String,)>,
+) -> WritingsApiResult<Json<Entity>> {
+    Entity::find(&id)
+        .cloned()
+        .map(Json)
+        .ok_or(WritingsApiError::NotFound)
+}
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct MentionsQuery {
+    pub work: Option<Work>,
+    #[validate(range(min = 1.0, max = 1000.0))]
+    #[serde(default = "default_limit")]
+    #[param(default = default_limit, maximum = 1000, minimum = 1)]
+    pub limit: usize,
+    #[serde(default)]
+    #[param(default = 0)]
+    pub offset: usize,
+}
+
+fn default_limit() -> usize {
+    DEFAULT_LIMIT
+}
+
+/// A page of the mentions of a person or place.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionsResponse {
+    #[serde(flatten)]
+    pub pagination: Pagination,
+    pub entity: Entity,
+    /// The mentions in document order.
+    pub mentions: Vec<Mention>,
+}
+
+/// Every mention of a person or place in the Writings, in document order,
+/// ignoring typography and diacritics.
+#[utoipa::path(
+    get,
+    path = "/{id}/mentions",
+    tag = api_tag(),
+    params(
+        ("id" = String, Path, example = "carmel"),
+        MentionsQuery,
+    ),
+    responses(
+        (status = OK, body = MentionsResponse, description = "Mentions of the person or place"),
+        (status = NOT_FOUND, description = "Entity not found"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn entity_mentions(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((id,)): Path<(String,)>,
+    Validated(Query(query)): Validated<Query<MentionsQuery>>,
+) -> WritingsApiResult<Json<MentionsResponse>> {
+    let entity = Entity::find(&id).ok_or(WritingsApiError::NotFound)?;
+    let mentions = entity
+        .mentions()
+        .iter()
+        .filter(|m| query.work.is_none_or(|work| m.work == work))
+        .collect::<Vec<_>>();
+
+    Ok(Json(MentionsResponse {
+        pagination: Pagination {
+            limit: query.limit,
+            offset: query.offset,
+            total: mentions.len(),
+        },
+        entity: entity.clone(),
+        mentions: mentions
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .cloned()
+            .collect(),
+    }))
+}
@@ -1,9 +1,18 @@
-use axum::{Json, extract::Path};
+use axum::{
+    Json,
+    extract::{Path, Query},
+};
+use axum_valid::Validated;
+use serde_json::Value;
 use utoipa::OpenApi as DeriveOpenApi;
 use utoipa_axum::{router::OpenApiRouter, routes};
-use writings::{EmbedAllTrait as _, GleaningsParagraph};
+use writings::{EmbedAllTrait as _, GleaningsParagraph, WritingsType};
 
-use crate::{WritingsApiError, WritingsApiResult, api_tag, roman_number::RomanNumber};
+use crate::{
+    WritingsApiError, WritingsApiResult, api_tag,
+    pagination::{Page, PageQuery},
+    roman_number::RomanNumber,
+};
 
 #[derive(DeriveOpenApi)]
 #[openapi(components(schemas(GleaningsParagraph, RomanNumber)))]
@@ -20,33 +29,40 @@ pub fn router() -> OpenApiRouter {
     get,
     path = "/",
     tag = api_tag(),
+    params(PageQuery),
     responses(
-        (status = OK, body = Vec<GleaningsParagraph>, description = "Gleanings Paragraphs"),
+        (status = OK, body = Page<GleaningsParagraph>, description = "Gleanings Paragraphs"),
     )
 )]
-pub async fn gleanings_all() -> WritingsApiResult<Json<Vec<GleaningsParagraph>>> {
-    Ok(Json(GleaningsParagraph::all().to_vec()))
+pub async fn gleanings_all(
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<GleaningsParagraph>(
+        &writings::Query::new().ty(WritingsType::Gleaning),
+    )?))
 }
 
 #[utoipa::path(
     get,
     path = "/{number}",
     tag = api_tag(),
+    params(PageQuery),
     responses(
-        (status = OK, body = Vec<GleaningsParagraph>, description = "Gleanings Paragraphs"),
+        (status = OK, body = Page<GleaningsParagraph>, description = "Gleanings Paragraphs"),
         (status = BAD_REQUEST, description = "bad request / invalid parameters")
     )
 )]
 pub async fn gleanings_by_number(
     // MUST be a tuple or it doesn't make it into spec.
     Path((number,)): Path<(RomanNumber,)>,
-) -> WritingsApiResult<Json<Vec<GleaningsParagraph>>> {
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
     Ok(Json(
-        GleaningsParagraph::all()
-            .iter()
-            .filter(|p| p.number == number.0)
-            .cloned()
-            .collect(),
+        page.page::<GleaningsParagraph>(
+            &writings::Query::new()
+                .ty(WritingsType::Gleaning)
+                .number(number.0..=number.0),
+        )?,
     ))
 }
 
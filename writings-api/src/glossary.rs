@@ -0,0 +1,74 @@
+use axum::{Json, extract::Path};
+use utoipa::OpenApi as DeriveOpenApi;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use writings::{EmbedAllTrait as _, GlossaryMatch, GlossaryTerm, Writings};
+
+use crate::{WritingsApiError, WritingsApiResult, api_tag};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(GlossaryTerm, GlossaryMatch)))]
+pub struct GlossaryApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(GlossaryApiDoc::openapi())
+        .routes(routes!(glossary_all))
+        .routes(routes!(glossary_term))
+        .routes(routes!(glossary_by_ref))
+}
+
+/// All terms of the glossary of transliterated Persian and Arabic terms,
+/// with their meanings and pronunciations.
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = api_tag(),
+    responses(
+        (status = OK, body = Vec<GlossaryTerm>, description = "The glossary"),
+    )
+)]
+pub async fn glossary_all() -> WritingsApiResult<Json<Vec<GlossaryTerm>>> {
+    Ok(Json(GlossaryTerm::all().to_vec()))
+}
+
+/// A term of the glossary, by its `id` or any spelling, ignoring case and diacritics.
+#[utoipa::path(
+    get,
+    path = "/{term}",
+    tag = api_tag(),
+    params(("term" = String, Path, example = "ridvan")),
+    responses(
+        (status = OK, body = GlossaryTerm, description = "The glossary term"),
+        (status = NOT_FOUND, description = "Term not found"),
+    )
+)]
+pub async fn glossary_term(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((term,)): Path<(String,)>,
+) -> WritingsApiResult<Json<GlossaryTerm>> {
+    GlossaryTerm::find(&term)
+        .cloned()
+        .map(Json)
+        .ok_or(WritingsApiError::NotFound)
+}
+
+/// The glossary terms in the text of the Writings with the `ref_id`, with their
+/// `char` offsets.
+#[utoipa::path(
+    get,
+    path = "/ref/{ref_id}",
+    tag = api_tag(),
+    params(("ref_id" = String, Path, example = "323776725")),
+    responses(
+        (status = OK, body = Vec<GlossaryMatch>, description = "The glossary terms in the text"),
+        (status = NOT_FOUND, description = "Writings not found"),
+    )
+)]
+pub async fn glossary_by_ref(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((ref_id,)): Path<(String,)>,
+) -> WritingsApiResult<Json<Vec<GlossaryMatch>>> {
+    Writings::all_map()
+        .get(&ref_id)
+        .map(|w| Json(w.glossary_terms()))
+        .ok_or(WritingsApiError::NotFound)
+}
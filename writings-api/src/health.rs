@@ -0,0 +1,35 @@
+use axum::{Json, http::StatusCode};
+use utoipa::OpenApi as DeriveOpenApi;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::{
+    api_tag,
+    search::{IndexHealth, IndexSource, IndexStatus, SearchState},
+};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(IndexHealth, IndexStatus, IndexSource)))]
+pub struct HealthApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(HealthApiDoc::openapi()).routes(routes!(get_health))
+}
+
+/// Readiness: whether the search index has loaded, from where, and how long it took.
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = api_tag(),
+    responses(
+        (status = OK, body = IndexHealth, description = "Ready"),
+        (status = SERVICE_UNAVAILABLE, body = IndexHealth, description = "Search index is loading or failed to load"),
+    )
+)]
+pub async fn get_health() -> (StatusCode, Json<IndexHealth>) {
+    let health = SearchState::get().health();
+    let status = match health.status {
+        IndexStatus::Ready => StatusCode::OK,
+        IndexStatus::Loading | IndexStatus::Failed => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    (status, Json(health))
+}
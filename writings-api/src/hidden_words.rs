@@ -1,10 +1,20 @@
-use axum::{Json, extract::Path};
+use axum::{
+    Json,
+    extract::{Path, Query},
+};
+use axum_valid::Validated;
 use serde::Deserialize;
+use serde_json::Value;
 use utoipa::{IntoParams, OpenApi as DeriveOpenApi};
 use utoipa_axum::{router::OpenApiRouter, routes};
-use writings::{EmbedAllTrait as _, HiddenWord, HiddenWordKind};
+use writings::{EmbedAllTrait as _, HiddenWord, HiddenWordKind, WritingsType};
 
-use crate::{WritingsApiResult, api_result::WritingsApiError, api_tag};
+use crate::{
+    WritingsApiResult,
+    api_result::WritingsApiError,
+    api_tag,
+    pagination::{Page, PageQuery},
+};
 
 #[derive(DeriveOpenApi)]
 #[openapi(components(schemas(HiddenWordKind, HiddenWord)))]
@@ -21,34 +31,36 @@ pub fn router() -> OpenApiRouter {
     get,
     path = "/",
     tag = api_tag(),
+    params(PageQuery),
     responses(
-        (status = OK, body = Vec<HiddenWord>, description = "Hidden Words"),
+        (status = OK, body = Page<HiddenWord>, description = "Hidden Words"),
     )
 )]
-pub async fn hidden_words_all() -> WritingsApiResult<Json<Vec<HiddenWord>>> {
-    Ok(Json(HiddenWord::all().to_vec()))
+pub async fn hidden_words_all(
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<HiddenWord>(
+        &writings::Query::new().ty(WritingsType::HiddenWord),
+    )?))
 }
 
 #[utoipa::path(
     get,
     path = "/{kind}",
     tag = api_tag(),
-    params(("kind" = HiddenWordKind, Path)),
+    params(("kind" = HiddenWordKind, Path), PageQuery),
     responses(
-        (status = OK, body = Vec<HiddenWord>, description = "Hidden Words"),
+        (status = OK, body = Page<HiddenWord>, description = "Hidden Words"),
         (status = BAD_REQUEST, description = "bad request / invalid parameters")
     )
 )]
 pub async fn hidden_words_by_kind(
     Path(kind): Path<HiddenWordKind>,
-) -> WritingsApiResult<Json<Vec<HiddenWord>>> {
-    Ok(Json(
-        HiddenWord::all()
-            .iter()
-            .filter(|hw| hw.kind == kind)
-            .cloned()
-            .collect(),
-    ))
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<HiddenWord>(
+        &writings::Query::new().hidden_word_kind(kind),
+    )?))
 }
 
 #[derive(Deserialize, IntoParams)]
@@ -2,14 +2,23 @@
 
 mod api_result;
 pub mod by_ref;
+pub mod concordance;
+pub mod entities;
 pub mod gleanings;
+pub mod glossary;
+pub mod health;
 pub mod hidden_words;
 pub mod meditations;
 pub mod pagination;
 pub mod prayers;
 pub mod roman_number;
 pub mod search;
+pub mod stats;
+pub mod toc;
 mod util;
+pub mod verify;
+pub mod works;
+pub mod writings_list;
 
 pub use api_result::{WritingsApiError, WritingsApiResult};
 use axum::{ServiceExt, extract::Request};
@@ -43,12 +52,21 @@ pub fn build_openapi_router(tag: Option<&str>) -> OpenApiRouter {
         })
     });
     OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .nest("/health", health::router())
         .nest("/hidden-words", hidden_words::router())
         .nest("/prayers", prayers::router())
+        .nest("/concordance", concordance::router())
+        .nest("/entities", entities::router())
         .nest("/gleanings", gleanings::router())
+        .nest("/glossary", glossary::router())
         .nest("/meditations", meditations::router())
         .nest("/ref", by_ref::router())
         .nest("/search", search::router())
+        .nest("/stats", stats::router())
+        .nest("/toc", toc::router())
+        .nest("/verify", verify::router())
+        .nest("/works", works::router())
+        .nest("/writings", writings_list::router())
 }
 
 pub async fn serve() -> WritingsApiResult<()> {
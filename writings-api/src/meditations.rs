@@ -1,9 +1,18 @@
-use axum::{Json, extract::Path};
+use axum::{
+    Json,
+    extract::{Path, Query},
+};
+use axum_valid::Validated;
+use serde_json::Value;
 use utoipa::OpenApi as DeriveOpenApi;
 use utoipa_axum::{router::OpenApiRouter, routes};
-use writings::{EmbedAllTrait as _, MeditationParagraph};
+use writings::{EmbedAllTrait as _, MeditationParagraph, WritingsType};
 
-use crate::{WritingsApiError, WritingsApiResult, api_tag, roman_number::RomanNumber};
+use crate::{
+    WritingsApiError, WritingsApiResult, api_tag,
+    pagination::{Page, PageQuery},
+    roman_number::RomanNumber,
+};
 
 #[derive(DeriveOpenApi)]
 #[openapi(components(schemas(MeditationParagraph)))]
@@ -20,12 +29,17 @@ pub fn router() -> OpenApiRouter {
     get,
     path = "/",
     tag = api_tag(),
+    params(PageQuery),
     responses(
-        (status = OK, body = Vec<MeditationParagraph>, description = "Prayer Paragraphs"),
+        (status = OK, body = Page<MeditationParagraph>, description = "Prayer Paragraphs"),
     )
 )]
-pub async fn meditations_all() -> WritingsApiResult<Json<Vec<MeditationParagraph>>> {
-    Ok(Json(MeditationParagraph::all().to_vec()))
+pub async fn meditations_all(
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<MeditationParagraph>(
+        &writings::Query::new().ty(WritingsType::Meditation),
+    )?))
 }
 
 #[utoipa::path(
@@ -33,21 +47,23 @@ pub async fn meditations_all() -> WritingsApiResult<Json<Vec<MeditationParagraph
     path = "/{number}",
     tag = api_tag(),
     // params(RomanNumber),
+    params(PageQuery),
     responses(
-        (status = OK, body = Vec<MeditationParagraph>, description = "Meditations Paragraphs"),
+        (status = OK, body = Page<MeditationParagraph>, description = "Meditations Paragraphs"),
         (status = BAD_REQUEST, description = "bad request / invalid parameters")
     )
 )]
 #[axum::debug_handler]
 pub async fn meditations_by_number(
     Path((number,)): Path<(RomanNumber,)>,
-) -> WritingsApiResult<Json<Vec<MeditationParagraph>>> {
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
     Ok(Json(
-        MeditationParagraph::all()
-            .iter()
-            .filter(|p| p.number == number.0)
-            .cloned()
-            .collect(),
+        page.page::<MeditationParagraph>(
+            &writings::Query::new()
+                .ty(WritingsType::Meditation)
+                .number(number.0..=number.0),
+        )?,
     ))
 }
 
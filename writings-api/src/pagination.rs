@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+use validify::Validify;
+use writings::{EmbedAllTrait as _, Query, Writings};
+
+use crate::{WritingsApiError, WritingsApiResult};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct Pagination {
@@ -7,3 +12,206 @@ pub struct Pagination {
     pub offset: usize,
     pub total: usize,
 }
+
+/// A page of a list of Writings.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    #[serde(flatten)]
+    pub pagination: Pagination,
+    /// Pass as `cursor` to get the next page; absent on the last page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    pub writings: Vec<T>,
+}
+
+const DEFAULT_LIMIT: usize = 100;
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct PageQuery {
+    #[validate(range(min = 1.0, max = 1000.0))]
+    #[serde(default = "default_limit")]
+    #[param(default = default_limit, maximum = 1000, minimum = 1)]
+    pub limit: usize,
+    /// Not allowed with `cursor`.
+    #[param(default = 0)]
+    pub offset: Option<usize>,
+    /// The opaque `nextCursor` of the previous page.
+    pub cursor: Option<String>,
+    /// Comma-separated fields to include in each item, e.g. `refId,text`; all if omitted.
+    #[param(example = "refId,number,text")]
+    pub fields: Option<String>,
+}
+
+fn default_limit() -> usize {
+    DEFAULT_LIMIT
+}
+
+impl PageQuery {
+    /// Execute the query and return the requested page, with each item converted to `T`
+    /// and trimmed to the requested `fields`.
+    pub fn page<T>(&self, query: &Query) -> WritingsApiResult<Page<Value>>
+    where
+        T: TryFrom<Writings> + Serialize,
+    {
+        let positions = query.positions();
+        let total = positions.len();
+
+        let start = match &self.cursor {
+            Some(_) if self.offset.is_some() => {
+                return Err(WritingsApiError::BadRequest(
+                    "offset and cursor cannot be combined".to_string(),
+                ));
+            }
+            Some(cursor) => {
+                let position: usize = cursor.parse().map_err(|_| {
+                    WritingsApiError::BadRequest(format!("invalid cursor: {cursor}"))
+                })?;
+                positions.partition_point(|&p| p < position)
+            }
+            None => self.offset.unwrap_or_default().min(total),
+        };
+        let end = (start + self.limit).min(total);
+
+        let all = Writings::all();
+        let writings = positions[start..end]
+            .iter()
+            .filter_map(|&p| T::try_from(all[p].clone()).ok())
+            .map(|item| self.sparse(item))
+            .collect::<WritingsApiResult<Vec<_>>>()?;
+
+        Ok(Page {
+            pagination: Pagination {
+                limit: self.limit,
+                offset: start,
+                total,
+            },
+            next_cursor: positions.get(end).map(|p| p.to_string()),
+            writings,
+        })
+    }
+
+    fn sparse(&self, item: impl Serialize) -> WritingsApiResult<Value> {
+        let mut value = serde_json::to_value(item)?;
+        if let (Some(fields), Value::Object(map)) = (&self.fields, &mut value) {
+            let fields = fields
+                .split(',')
+                .map(|f| camel_case(f.trim()))
+                .collect::<Vec<_>>();
+            map.retain(|key, _| fields.contains(key));
+        }
+        Ok(value)
+    }
+}
+
+/// Accept `snake_case` field names as well as the serialized `camelCase`.
+fn camel_case(field: &str) -> String {
+    let mut parts = field.split('_');
+    let first = parts.next().unwrap_or_default().to_string();
+    parts.fold(first, |mut s, part| {
+        let mut chars = part.chars();
+        if let Some(c) = chars.next() {
+            s.extend(c.to_uppercase());
+            s.push_str(chars.as_str());
+        }
+        s
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt as _;
+    use utoipa_axum::router::OpenApiRouter;
+    use writings::{Query, WritingsType};
+
+    use crate::{hidden_words, prayers, writings_list};
+
+    async fn get(uri: &str) -> (StatusCode, Value) {
+        let (router, _) = OpenApiRouter::new()
+            .nest("/hidden-words", hidden_words::router())
+            .nest("/prayers", prayers::router())
+            .nest("/writings", writings_list::router())
+            .split_for_parts();
+        let response = router
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    fn ref_ids(page: &Value) -> Vec<&str> {
+        page["writings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| w["refId"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_cursor() {
+        let (status, first) = get("/prayers?limit=2").await;
+        assert_eq!(status, StatusCode::OK);
+        let cursor = first["nextCursor"].as_str().unwrap();
+
+        let (status, next) = get(&format!("/prayers?limit=2&cursor={cursor}")).await;
+        assert_eq!(status, StatusCode::OK);
+        let (_, by_offset) = get("/prayers?limit=2&offset=2").await;
+        assert_eq!(next["offset"], 2);
+        assert_eq!(next["total"], first["total"]);
+        assert_eq!(ref_ids(&next), ref_ids(&by_offset));
+        assert!(
+            ref_ids(&next)
+                .iter()
+                .all(|id| !ref_ids(&first).contains(id))
+        );
+
+        // The last page has no cursor.
+        let (_, all) = get("/hidden-words?limit=1000").await;
+        assert_eq!(all["writings"].as_array().unwrap().len(), all["total"]);
+        assert!(all.get("nextCursor").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_offset_and_cursor() {
+        let (status, _) = get("/prayers?offset=2&cursor=5").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = get("/prayers?cursor=next").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_total() {
+        let prayers = Query::new().ty(WritingsType::Prayer).positions().len();
+        let (_, page) = get("/writings?type=prayer&limit=1").await;
+        assert_eq!(page["total"], prayers);
+        assert_eq!(page["writings"].as_array().unwrap().len(), 1);
+
+        let (_, page) = get("/writings?type=prayer&kind=obligatory&limit=1").await;
+        let obligatory = page["total"].as_u64().unwrap() as usize;
+        assert!(obligatory > 0 && obligatory < prayers);
+        let (_, all) = get("/writings?limit=1").await;
+        assert!(all["total"].as_u64().unwrap() as usize > prayers);
+    }
+
+    #[tokio::test]
+    async fn test_fields() {
+        for fields in ["refId,text", "ref_id,%20text"] {
+            let (status, page) = get(&format!("/prayers?limit=3&fields={fields}")).await;
+            assert_eq!(status, StatusCode::OK);
+            for w in page["writings"].as_array().unwrap() {
+                let mut keys = w.as_object().unwrap().keys().collect::<Vec<_>>();
+                keys.sort();
+                assert_eq!(keys, ["refId", "text"], "{fields}");
+            }
+        }
+    }
+}
@@ -1,11 +1,20 @@
-use axum::{Json, extract::Path, routing::get};
-use diacritics::remove_diacritics;
+use axum::{
+    Json,
+    extract::{Path, Query},
+    routing::get,
+};
+use axum_valid::Validated;
 use serde::Deserialize;
+use serde_json::Value;
 use utoipa::{IntoParams, OpenApi as DeriveOpenApi};
 use utoipa_axum::{router::OpenApiRouter, routes};
-use writings::{EmbedAllTrait as _, PrayerKind, PrayerParagraph};
+use writings::{PrayerKind, PrayerParagraph, WritingsType};
 
-use crate::{WritingsApiResult, api_tag};
+use crate::{
+    WritingsApiResult, api_tag,
+    pagination::{Page, PageQuery},
+    writings_list::section_query,
+};
 
 #[derive(DeriveOpenApi)]
 // Register prayers_by_kind_section in OpenAPI properly for Swagger UI
@@ -26,34 +35,36 @@ pub fn router() -> OpenApiRouter {
     get,
     path = "/",
     tag = api_tag(),
+    params(PageQuery),
     responses(
-        (status = OK, body = Vec<PrayerParagraph>, description = "Prayer Paragraphs"),
+        (status = OK, body = Page<PrayerParagraph>, description = "Prayer Paragraphs"),
     )
 )]
-pub async fn prayers_all() -> WritingsApiResult<Json<Vec<PrayerParagraph>>> {
-    Ok(Json(PrayerParagraph::all().to_vec()))
+pub async fn prayers_all(
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<PrayerParagraph>(
+        &writings::Query::new().ty(WritingsType::Prayer),
+    )?))
 }
 
 #[utoipa::path(
     get,
     path = "/{kind}",
     tag = api_tag(),
-    params(("kind" = PrayerKind, Path)),
+    params(("kind" = PrayerKind, Path), PageQuery),
     responses(
-        (status = OK, body = Vec<PrayerParagraph>, description = "Prayer Paragraphs"),
+        (status = OK, body = Page<PrayerParagraph>, description = "Prayer Paragraphs"),
         (status = BAD_REQUEST, description = "bad request / invalid parameters")
     )
 )]
 pub async fn prayers_by_kind(
     Path(kind): Path<PrayerKind>,
-) -> WritingsApiResult<Json<Vec<PrayerParagraph>>> {
-    Ok(Json(
-        PrayerParagraph::all()
-            .iter()
-            .filter(|p| p.kind == kind)
-            .cloned()
-            .collect(),
-    ))
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<PrayerParagraph>(
+        &writings::Query::new().prayer_kind(kind),
+    )?))
 }
 
 #[derive(Deserialize, IntoParams)]
@@ -70,40 +81,18 @@ pub struct PrayersKindSectionPath {
     // register as a wildcard in axum, hence we register it twice.
     path = "/{kind}/{section}",
     tag = api_tag(),
-    params(PrayersKindSectionPath),
+    params(PrayersKindSectionPath, PageQuery),
     responses(
-        (status = OK, body = Vec<PrayerParagraph>, description = "Prayer Paragraphs"),
+        (status = OK, body = Page<PrayerParagraph>, description = "Prayer Paragraphs"),
         (status = BAD_REQUEST, description = "bad request / invalid parameters")
     )
 )]
 pub async fn prayers_by_kind_section(
     Path(PrayersKindSectionPath { kind, section }): Path<PrayersKindSectionPath>,
-) -> WritingsApiResult<Json<Vec<PrayerParagraph>>> {
-    let path_sections: Vec<String> = section
-        .split('/')
-        .map(|s| remove_diacritics(&s.replace('-', " ")).to_lowercase())
-        .collect();
-
-    Ok(Json(
-        PrayerParagraph::all()
-            .iter()
-            .filter(|p| p.kind == kind)
-            .filter(|p| {
-                // Convert prayer sections to normalized form (lowercase, no diacritics)
-                let normalized_prayer_sections: Vec<String> = p
-                    .section
-                    .iter()
-                    .map(|s| remove_diacritics(s).to_lowercase())
-                    .collect();
-
-                // Check if each path section matches some prayer section
-                path_sections.iter().all(|path_section| {
-                    normalized_prayer_sections
-                        .iter()
-                        .any(|prayer_section| prayer_section.contains(path_section))
-                })
-            })
-            .cloned()
-            .collect(),
-    ))
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<PrayerParagraph>(&section_query(
+        writings::Query::new().prayer_kind(kind),
+        &section,
+    ))?))
 }
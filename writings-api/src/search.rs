@@ -1,38 +1,231 @@
-use crate::{WritingsApiResult, api_tag, pagination::Pagination};
+use crate::{
+    WritingsApiError, WritingsApiResult, api_tag, pagination::Pagination, writings_list::Kind,
+};
 use axum::{
     Json,
     extract::{Query, State},
 };
 use axum_valid::Validated;
-use diacritics::remove_diacritics;
-use indicium::simple::{
-    AutocompleteType, RapidfuzzMetric, SearchIndex, SearchIndexBuilder, SearchType,
-};
-use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::sync::LazyLock;
+use std::{
+    sync::{Arc, Mutex, OnceLock},
+    time::Instant,
+};
 use utoipa::{IntoParams, OpenApi as DeriveOpenApi, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
 use validify::Validify;
-use writings::{Author, EmbedAllTrait as _, Writings, WritingsTrait as _, WritingsType};
+use writings::{
+    Author, Work, WritingsType,
+    search::{
+        Completion, Concordance, RelatedIndex, SearchEngine, SearchFacets, SearchFilter,
+        SearchQuery as ParsedQuery, SearchResult, SearchWeights,
+    },
+};
 
 #[derive(DeriveOpenApi)]
-#[openapi(components(schemas()))]
+#[openapi(components(schemas(SearchResult, SearchFacets, Completion)))]
 pub struct SearchApiDoc;
 
 pub fn router() -> OpenApiRouter {
-    let index = build_search_index();
-
     OpenApiRouter::with_openapi(SearchApiDoc::openapi())
         .routes(routes!(get_search))
-        .with_state(index)
+        .routes(routes!(get_autocomplete))
+        .with_state(SearchState::get())
+}
+
+/// Whether the search index is ready, see [`SearchState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum IndexStatus {
+    Loading,
+    Ready,
+    Failed,
+}
+
+/// Where the search index was loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum IndexSource {
+    /// The file in `SEARCH_INDEX_PATH`, saved by `writings-update search-index`.
+    File,
+    /// Indexed on start (and saved to `SEARCH_INDEX_PATH`, if set).
+    Built,
+    /// The tantivy index in `SEARCH_INDEX_DIR`.
+    Tantivy,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexHealth {
+    pub status: IndexStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<IndexSource>,
+    /// How long loading the index took, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_millis: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The search engine, loaded in the background so the API can serve everything else
+/// (and `/health`) while the Writings are indexed, and then the related index and the
+/// concordance.
+pub struct SearchState {
+    engine: OnceLock<SearchEngine>,
+    related: OnceLock<RelatedIndex>,
+    concordance: OnceLock<Concordance>,
+    health: Mutex<IndexHealth>,
+}
+
+static SEARCH_STATE: OnceLock<Arc<SearchState>> = OnceLock::new();
+
+impl SearchState {
+    /// The shared state, loading the search engine in a background thread on first use.
+    pub fn get() -> Arc<SearchState> {
+        SEARCH_STATE
+            .get_or_init(|| {
+                let state = Arc::new(SearchState {
+                    engine: OnceLock::new(),
+                    related: OnceLock::new(),
+                    concordance: OnceLock::new(),
+                    health: Mutex::new(IndexHealth {
+                        status: IndexStatus::Loading,
+                        source: None,
+                        load_millis: None,
+                        error: None,
+                    }),
+                });
+                let loading = state.clone();
+                std::thread::spawn(move || loading.load());
+                state
+            })
+            .clone()
+    }
+
+    /// The search engine, or [`WritingsApiError::Unavailable`] until it is loaded.
+    pub fn engine(&self) -> WritingsApiResult<&SearchEngine> {
+        self.engine.get().ok_or_else(|| {
+            WritingsApiError::Unavailable(format!("search index is {}", self.health().status))
+        })
+    }
+
+    /// The "more like this" index, or [`WritingsApiError::Unavailable`] until it is built
+    /// after the search engine.
+    pub fn related(&self) -> WritingsApiResult<&RelatedIndex> {
+        self.related
+            .get()
+            .ok_or_else(|| self.unavailable("related index"))
+    }
+
+    /// The concordance, or [`WritingsApiError::Unavailable`] until it is built after the
+    /// related index.
+    pub fn concordance(&self) -> WritingsApiResult<&Concordance> {
+        self.concordance
+            .get()
+            .ok_or_else(|| self.unavailable("concordance"))
+    }
+
+    /// An index built after the search engine is loading while the search engine is ready.
+    fn unavailable(&self, name: &str) -> WritingsApiError {
+        let status = match self.health().status {
+            IndexStatus::Ready => IndexStatus::Loading,
+            status => status,
+        };
+        WritingsApiError::Unavailable(format!("{name} is {status}"))
+    }
+
+    pub fn health(&self) -> IndexHealth {
+        self.health
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn load(&self) {
+        println!("Loading search index...");
+        let start = Instant::now();
+        let result = std::panic::catch_unwind(search_engine);
+        let mut health = self.health.lock().unwrap_or_else(|e| e.into_inner());
+        health.load_millis = Some(start.elapsed().as_millis() as u64);
+        match result {
+            Ok((engine, source)) => {
+                _ = self.engine.set(engine);
+                health.status = IndexStatus::Ready;
+                health.source = Some(source);
+                println!("Search index ready ({source}) in {:?}", start.elapsed());
+            }
+            Err(panic) => {
+                health.status = IndexStatus::Failed;
+                health.error = panic
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| panic.downcast_ref::<String>().cloned());
+                eprintln!("Failed to load search index: {:?}", health.error);
+            }
+        }
+        drop(health);
+
+        if let Some(engine) = self.engine.get() {
+            let start = Instant::now();
+            _ = self.related.set(RelatedIndex::new(engine.analyzer()));
+            println!("Related index ready in {:?}", start.elapsed());
+
+            let start = Instant::now();
+            _ = self.concordance.set(Concordance::new(engine.analyzer()));
+            println!("Concordance ready in {:?}", start.elapsed());
+        }
+    }
+}
+
+/// Load the analysis saved in `SEARCH_INDEX_PATH` and index it, or index the Writings and
+/// save their analysis there.
+#[cfg(not(feature = "tantivy"))]
+fn search_engine() -> (SearchEngine, IndexSource) {
+    let Ok(path) = std::env::var("SEARCH_INDEX_PATH") else {
+        return (SearchEngine::default(), IndexSource::Built);
+    };
+    match SearchEngine::load(&path, SearchWeights::default()) {
+        Ok(engine) => return (engine, IndexSource::File),
+        Err(e) => eprintln!("Cannot load search index from {path}: {e}"),
+    }
+    let engine = SearchEngine::default();
+    if let Err(e) = engine.save(&path) {
+        eprintln!("Cannot save search index to {path}: {e}");
+    }
+    (engine, IndexSource::Built)
+}
+
+/// Search the tantivy index in `SEARCH_INDEX_DIR`, building it there if it is missing or
+/// out of date, or the default engine if it cannot be opened.
+#[cfg(feature = "tantivy")]
+fn search_engine() -> (SearchEngine, IndexSource) {
+    use writings::search::{Analyzer, TantivyIndex};
+
+    let directory = crate::util::get_from_env("SEARCH_INDEX_DIR", "search-index".to_string());
+    let analyzer = Analyzer::default();
+    match TantivyIndex::open_or_build(&directory, &analyzer) {
+        Ok(index) => (
+            SearchEngine::with_tantivy(SearchWeights::default(), analyzer, index),
+            IndexSource::Tantivy,
+        ),
+        Err(e) => {
+            eprintln!("Cannot open search index in {directory}: {e}");
+            (SearchEngine::default(), IndexSource::Built)
+        }
+    }
 }
 
 const DEFAULT_LIMIT: usize = 9;
+const MAX_SUGGESTIONS: usize = 3;
 
 #[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
 #[into_params(parameter_in = Query)]
 pub struct SearchQuery {
+    /// Words, `"exact phrases"`, `"proximity phrases"~n`, `-exclusions`, `OR`,
+    /// and field filters `author:`, `type:`, `kind:` and `work:`.
+    #[param(example = "\"tabernacle of unity\" OR oneness -fire author:bahaullah")]
     pub q: String,
     #[validate(range(min = 1.0, max = 95.0))]
     #[serde(default = "default_limit")]
@@ -41,6 +234,39 @@ pub struct SearchQuery {
     #[serde(default)]
     #[param(default = 0)]
     pub offset: usize,
+    /// Only hits of this type. Like the other facet parameters, this narrows the results of
+    /// `q`, including its field filters: `q=mercy type:prayer&type=gleaning` has no results.
+    #[serde(rename = "type")]
+    #[param(rename = "type")]
+    pub ty: Option<WritingsType>,
+    pub author: Option<Author>,
+    #[param(inline)]
+    pub kind: Option<Kind>,
+    pub work: Option<Work>,
+    /// Collapse hits from the same selection (prayer, Gleaning, etc.) into the best one.
+    #[serde(default)]
+    #[param(default = false)]
+    pub collapse: bool,
+    /// Include the components of each `score`.
+    #[serde(default)]
+    #[param(default = false)]
+    pub explain: bool,
+}
+
+impl SearchQuery {
+    fn facets(&self) -> impl Iterator<Item = SearchFilter> {
+        [
+            self.ty.map(SearchFilter::Type),
+            self.author.map(SearchFilter::Author),
+            self.kind.map(|kind| match kind {
+                Kind::Prayer(kind) => SearchFilter::PrayerKind(kind),
+                Kind::HiddenWord(kind) => SearchFilter::HiddenWordKind(kind),
+            }),
+            self.work.map(SearchFilter::Work),
+        ]
+        .into_iter()
+        .flatten()
+    }
 }
 
 fn default_limit() -> usize {
@@ -51,34 +277,12 @@ fn default_limit() -> usize {
 pub struct SearchResults {
     #[serde(flatten)]
     pub pagination: Pagination,
-    pub writings: Vec<WritingsResult>,
-}
-
-#[derive(Debug, Serialize, ToSchema)]
-pub struct WritingsResult {
-    #[serde(skip)]
-    pub score: usize,
-    pub ty: WritingsType,
-    pub author: Author,
-    pub excerpt: String,
-    pub item: Writings,
-}
-
-impl WritingsResult {
-    pub fn new(writings: Writings, excerpt: (usize, String)) -> Self {
-        utoipa::openapi::ObjectBuilder::new().extensions(Some(
-            utoipa::openapi::extensions::Extensions::builder()
-                .add("x-enum-descriptions", vec!["one", "two", "three"])
-                .build(),
-        ));
-        Self {
-            score: excerpt.0,
-            excerpt: excerpt.1,
-            ty: writings.ty(),
-            author: writings.author(),
-            item: writings,
-        }
-    }
+    /// Counts of all hits (not only this page), including collapsed hits.
+    pub facets: SearchFacets,
+    pub writings: Vec<SearchResult>,
+    /// "Did you mean" corrections of `q` with results, when it has none.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
 }
 
 /// [`SearchQuery`]
@@ -89,187 +293,83 @@ impl WritingsResult {
     params(SearchQuery),
     responses(
         (status = OK, body = SearchResults, description = "Search results"),
+        (status = BAD_REQUEST, description = "invalid search query / parameters"),
+        (status = SERVICE_UNAVAILABLE, description = "search index is loading")
     )
 )]
 #[axum::debug_handler]
 pub async fn get_search(
     Validated(Query(query)): Validated<Query<SearchQuery>>,
-    State(index): State<SearchIndex<String>>,
+    State(state): State<Arc<SearchState>>,
 ) -> WritingsApiResult<Json<SearchResults>> {
-    let mut writings = search(&index, &query);
+    let engine = state.engine()?;
+    let mut parsed: ParsedQuery = query
+        .q
+        .parse()
+        .map_err(|e: writings::WritingsError| WritingsApiError::BadRequest(e.to_string()))?;
+    parsed.facets.extend(query.facets());
+
+    let mut writings = engine.search_query(&parsed);
+    if query.collapse {
+        writings = SearchResult::collapse(writings);
+    }
+    let facets = SearchFacets::new(&writings);
     let total = writings.len();
+    let suggestions = match total {
+        0 => engine.did_you_mean(&query.q, MAX_SUGGESTIONS),
+        _ => vec![],
+    };
     let start = query.offset.min(total);
     let end = (query.offset + query.limit).min(total);
-    let writings: Vec<_> = writings.drain(start..end).collect();
+    let writings: Vec<_> = writings
+        .drain(start..end)
+        .map(|mut result| {
+            if !query.explain {
+                result.explanation = None;
+            }
+            result
+        })
+        .collect();
     Ok(Json(SearchResults {
         pagination: Pagination {
             limit: query.limit,
             offset: query.offset,
-            total: writings.len(),
+            total,
         },
+        facets,
         writings,
+        suggestions,
     }))
 }
 
-static WORD_BOUNDARY_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b").unwrap());
-static SENTENCE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[^.!?]+[.!?]?\s*").unwrap());
-
-fn search(index: &SearchIndex<String>, query: &SearchQuery) -> Vec<WritingsResult> {
-    const ORDER_WEIGHT: f64 = 800.0;
-    const PROXIMITY_WEIGHT: f64 = 600.0;
-    const POSITION_WEIGHT: f64 = 400.0;
-    const EXACT_LAST_WEIGHT: f64 = 1000.0;
-    const FUZZY_WEIGHT: f64 = 500.0;
-
-    let query = remove_diacritics(&query.q);
-    let keywords = split_into_words(&query);
-
-    if keywords.is_empty() {
-        return vec![];
-    }
-
-    // get all but the last keyword
-    let must_match = &keywords[..keywords.len() - 1];
-    let last_keyword = &keywords[keywords.len() - 1];
-
-    let writings = Writings::all_map();
-
-    let mut writings_results = index
-        .search_type(&SearchType::Live, &query)
-        .iter()
-        .filter_map(|&ref_id| {
-            writings.get(ref_id).and_then(|w| {
-                SENTENCE_REGEX
-                    .find_iter(&w.text())
-                    .map(|m| m.as_str().trim())
-                    .filter(|s| {
-                        !s.is_empty() && {
-                            let s_lower = s.to_lowercase();
-                            must_match.iter().all(|k| s_lower.contains(k))
-                        }
-                    })
-                    .filter_map(|s| {
-                        let s_lower = s.to_lowercase();
-                        let words = split_into_words(&s_lower);
-
-                        // 1. Keyword positions and presence checks
-                        let mut positions = Vec::with_capacity(keywords.len());
-
-                        // Track must-match keywords
-                        for kw in must_match {
-                            if let Some(pos) = words.iter().position(|word| word.contains(kw)) {
-                                positions.push(pos);
-                            } else {
-                                return None; // Should have been filtered earlier
-                            }
-                        }
-
-                        // 2. Last keyword handling with minimum fuzzy threshold
-                        let (last_present, last_pos, fuzzy_score) = {
-                            if let Some(pos) =
-                                words.iter().position(|word| word.contains(last_keyword))
-                            {
-                                (true, pos, 1.0)
-                            } else {
-                                // Require minimum fuzzy score of 0.7 similarity
-                                let (best_score, best_pos) = words
-                                    .iter()
-                                    .enumerate()
-                                    .map(|(pos, word)| {
-                                        let score = rapidfuzz::distance::lcs_seq::similarity(
-                                            last_keyword.chars(),
-                                            word.chars(),
-                                        )
-                                            as f64
-                                            / last_keyword.len().max(word.len()) as f64;
-                                        (score, pos)
-                                    })
-                                    .max_by(|a, b| {
-                                        a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal)
-                                    })
-                                    .unwrap_or((0.0, 0));
-
-                                if best_score < 0.7 {
-                                    return None;
-                                }
-                                (false, best_pos, best_score)
-                            }
-                        };
-                        positions.push(last_pos);
-
-                        // 3. Scoring components
-                        // a. Order score (all keywords in sequence)
-                        let order_score = positions
-                            .windows(2)
-                            .filter(|pair| pair[1] >= pair[0])
-                            .count();
-
-                        // b. Proximity score (average distance between consecutive keywords)
-                        let proximity_score: f64 = positions
-                            .windows(2)
-                            .map(|pair| 1.0 / (pair[1].abs_diff(pair[0]) as f64 + 1.0))
-                            .sum::<f64>()
-                            / (positions.len() - 1).max(1) as f64;
-
-                        // c. Position bonus (earlier first keyword better)
-                        let position_score = 1.0 / (positions[0] as f64 + 1.0);
-
-                        // d. Exact match bonus for last keyword
-                        let exact_last_bonus = last_present as usize;
-
-                        let total_score = (order_score as f64 * ORDER_WEIGHT)
-                            + (proximity_score * PROXIMITY_WEIGHT)
-                            + (position_score * POSITION_WEIGHT)
-                            + (exact_last_bonus as f64 * EXACT_LAST_WEIGHT)
-                            + (fuzzy_score * FUZZY_WEIGHT);
-
-                        let total_score = total_score.round() as usize;
-
-                        Some((total_score, s.trim().to_string()))
-                    })
-                    .max_by_key(|s| s.0)
-                    .map(|excerpt| WritingsResult::new(w.clone(), excerpt))
-            })
-        })
-        .collect::<Vec<_>>();
-
-    writings_results.sort_by_key(|w| -(w.score as i64));
-
-    writings_results
-}
-
-fn build_search_index() -> SearchIndex<String> {
-    let mut index: SearchIndex<String> = SearchIndexBuilder::default()
-        .case_sensitive(false)
-        .autocomplete_type(AutocompleteType::Global)
-        .exclude_keywords(Some(
-            ["thee", "thou", "thine", "hast"]
-                .iter()
-                .map(|s| s.to_string())
-                .collect(),
-        ))
-        .fuzzy_length(3)
-        .max_autocomplete_options(9)
-        .max_search_results(95)
-        .rapidfuzz_metric(Some(RapidfuzzMetric::DamerauLevenshtein))
-        .fuzzy_minimum_score(0.3)
-        .build();
-
-    println!("Loading Writings...");
-    let writings = Writings::all_map();
-    println!("Done loading Writings! Indexing...");
-    writings.iter().for_each(|(ref_id, w)| {
-        index.insert(ref_id, w);
-    });
-    println!("Done indexing Writings!");
-    index
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct AutocompleteQuery {
+    /// The partial query; its last word may be incomplete.
+    #[param(example = "O Son of Sp")]
+    pub q: String,
+    #[validate(range(min = 1.0, max = 95.0))]
+    #[serde(default = "default_limit")]
+    #[param(default = default_limit, maximum = 95, minimum = 1)]
+    pub limit: usize,
 }
 
-fn split_into_words(input: &str) -> Vec<String> {
-    WORD_BOUNDARY_REGEX
-        .split(input)
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .map(|s| diacritics::remove_diacritics(s).to_lowercase())
-        .collect::<Vec<_>>()
+/// Completions of a partial query: words, and titles and invocations such as
+/// "O Son of Spirit", ignoring case and diacritics.
+#[utoipa::path(
+    get,
+    path = "/autocomplete",
+    tag = api_tag(),
+    params(AutocompleteQuery),
+    responses(
+        (status = OK, body = Vec<Completion>, description = "Completions, best first"),
+        (status = BAD_REQUEST, description = "invalid parameters"),
+        (status = SERVICE_UNAVAILABLE, description = "search index is loading")
+    )
+)]
+pub async fn get_autocomplete(
+    Validated(Query(query)): Validated<Query<AutocompleteQuery>>,
+    State(state): State<Arc<SearchState>>,
+) -> WritingsApiResult<Json<Vec<Completion>>> {
+    Ok(Json(state.engine()?.autocomplete(&query.q, query.limit)))
 }
@@ -0,0 +1,217 @@
+use axum::{Json, extract::Query};
+use axum_valid::Validated;
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi as DeriveOpenApi, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use validify::Validify;
+use writings::{
+    Author, EmbedAllTrait as _, Query as WritingsQuery, Work, Writings,
+    stats::{Collocation, DistinctiveTerm, Stats, TermCount, TextStats},
+};
+
+use crate::{WritingsApiError, WritingsApiResult, api_tag};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(TextStats, TermCount, Collocation, DistinctiveTerm)))]
+pub struct StatsApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(StatsApiDoc::openapi())
+        .routes(routes!(get_stats))
+        .routes(routes!(get_ngrams))
+        .routes(routes!(get_collocations))
+        .routes(routes!(get_distinctive))
+}
+
+const DEFAULT_LIMIT: usize = 50;
+const DEFAULT_N: usize = 2;
+const DEFAULT_MIN_COUNT: usize = 5;
+
+fn default_limit() -> usize {
+    DEFAULT_LIMIT
+}
+
+fn default_n() -> usize {
+    DEFAULT_N
+}
+
+fn default_min_count() -> usize {
+    DEFAULT_MIN_COUNT
+}
+
+/// The statistics of one paragraph, or of the Writings by the `author` and/or of the `work`,
+/// or of all Writings.
+fn selected(
+    ref_id: Option<&str>,
+    author: Option<Author>,
+    work: Option<Work>,
+) -> WritingsApiResult<Stats> {
+    if let Some(ref_id) = ref_id {
+        let w = Writings::all_map()
+            .get(ref_id)
+            .cloned()
+            .ok_or(WritingsApiError::NotFound)?;
+        return Ok(Stats::new([&w]));
+    }
+    Ok(match selection(author, work) {
+        Some(query) => Stats::of(&query),
+        None => Stats::all(),
+    })
+}
+
+fn selection(author: Option<Author>, work: Option<Work>) -> Option<WritingsQuery> {
+    if author.is_none() && work.is_none() {
+        return None;
+    }
+    let mut query = WritingsQuery::new();
+    if let Some(author) = author {
+        query = query.author(author);
+    }
+    if let Some(work) = work {
+        query = query.work(work);
+    }
+    Some(query)
+}
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct StatsQuery {
+    /// Only this paragraph; `author` and `work` are then ignored.
+    pub ref_id: Option<String>,
+    pub author: Option<Author>,
+    pub work: Option<Work>,
+}
+
+/// Counts of paragraphs, sentences and words, vocabulary, sentence lengths and reading time.
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = api_tag(),
+    params(StatsQuery),
+    responses(
+        (status = OK, body = TextStats, description = "Statistics of the selected Writings"),
+        (status = NOT_FOUND, description = "Writings not found"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn get_stats(
+    Validated(Query(query)): Validated<Query<StatsQuery>>,
+) -> WritingsApiResult<Json<TextStats>> {
+    let stats = selected(query.ref_id.as_deref(), query.author, query.work)?;
+    Ok(Json(stats.summary()))
+}
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct NgramsQuery {
+    /// The number of words of each n-gram.
+    #[validate(range(min = 1.0, max = 5.0))]
+    #[serde(default = "default_n")]
+    #[param(default = default_n, minimum = 1, maximum = 5)]
+    pub n: usize,
+    #[validate(range(min = 1.0, max = 1000.0))]
+    #[serde(default = "default_limit")]
+    #[param(default = default_limit, maximum = 1000, minimum = 1)]
+    pub limit: usize,
+    /// Only this paragraph; `author` and `work` are then ignored.
+    pub ref_id: Option<String>,
+    pub author: Option<Author>,
+    pub work: Option<Work>,
+}
+
+/// The most frequent sequences of `n` words that neither begin nor end with a function word.
+#[utoipa::path(
+    get,
+    path = "/ngrams",
+    tag = api_tag(),
+    params(NgramsQuery),
+    responses(
+        (status = OK, body = Vec<TermCount>, description = "N-grams, most frequent first"),
+        (status = NOT_FOUND, description = "Writings not found"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn get_ngrams(
+    Validated(Query(query)): Validated<Query<NgramsQuery>>,
+) -> WritingsApiResult<Json<Vec<TermCount>>> {
+    let stats = selected(query.ref_id.as_deref(), query.author, query.work)?;
+    Ok(Json(stats.ngrams(query.n, query.limit)))
+}
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct CollocationsQuery {
+    /// The minimum number of occurrences of each pair of words.
+    #[validate(range(min = 1.0, max = 1000.0))]
+    #[serde(default = "default_min_count")]
+    #[param(default = default_min_count, minimum = 1, maximum = 1000)]
+    pub min_count: usize,
+    #[validate(range(min = 1.0, max = 1000.0))]
+    #[serde(default = "default_limit")]
+    #[param(default = default_limit, maximum = 1000, minimum = 1)]
+    pub limit: usize,
+    /// Only this paragraph; `author` and `work` are then ignored.
+    pub ref_id: Option<String>,
+    pub author: Option<Author>,
+    pub work: Option<Work>,
+}
+
+/// Pairs of adjacent words that occur together more often than by chance.
+#[utoipa::path(
+    get,
+    path = "/collocations",
+    tag = api_tag(),
+    params(CollocationsQuery),
+    responses(
+        (status = OK, body = Vec<Collocation>, description = "Collocations, strongest first"),
+        (status = NOT_FOUND, description = "Writings not found"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn get_collocations(
+    Validated(Query(query)): Validated<Query<CollocationsQuery>>,
+) -> WritingsApiResult<Json<Vec<Collocation>>> {
+    let stats = selected(query.ref_id.as_deref(), query.author, query.work)?;
+    Ok(Json(stats.collocations(query.min_count, query.limit)))
+}
+
+#[derive(Debug, Deserialize, Validify, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct DistinctiveQuery {
+    pub author: Option<Author>,
+    pub work: Option<Work>,
+    /// Compare with this Author (and/or `other_work`), rather than all other Writings.
+    pub other_author: Option<Author>,
+    pub other_work: Option<Work>,
+    #[validate(range(min = 1.0, max = 1000.0))]
+    #[serde(default = "default_limit")]
+    #[param(default = default_limit, maximum = 1000, minimum = 1)]
+    pub limit: usize,
+}
+
+/// The words most over-represented in the Writings by the `author` and/or of the `work`,
+/// compared with all others (or with `other_author` and/or `other_work`).
+#[utoipa::path(
+    get,
+    path = "/distinctive",
+    tag = api_tag(),
+    params(DistinctiveQuery),
+    responses(
+        (status = OK, body = Vec<DistinctiveTerm>, description = "Distinctive terms, most distinctive first"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters; `author` or `work` is required")
+    )
+)]
+pub async fn get_distinctive(
+    Validated(Query(query)): Validated<Query<DistinctiveQuery>>,
+) -> WritingsApiResult<Json<Vec<DistinctiveTerm>>> {
+    let selected = selection(query.author, query.work).ok_or_else(|| {
+        WritingsApiError::BadRequest("`author` or `work` is required".to_string())
+    })?;
+    let other = match selection(query.other_author, query.other_work) {
+        Some(other) => Stats::of(&other),
+        None => Stats::except(&selected),
+    };
+    Ok(Json(
+        Stats::of(&selected).distinctive_terms(&other, query.limit),
+    ))
+}
@@ -0,0 +1,60 @@
+use axum::{Json, extract::Path};
+use utoipa::OpenApi as DeriveOpenApi;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use writings::{
+    CDBParagraph, EmbedAllTrait as _, GleaningsParagraph, HiddenWord, MeditationParagraph,
+    PrayerParagraph, Toc, Writings, WritingsType,
+};
+
+use crate::{WritingsApiError, WritingsApiResult, api_tag};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(Toc, WritingsType)))]
+pub struct TocApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(TocApiDoc::openapi())
+        .routes(routes!(toc_all))
+        .routes(routes!(toc_by_work))
+}
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = api_tag(),
+    responses(
+        (status = OK, body = Toc, description = "Table of contents of all Writings"),
+    )
+)]
+pub async fn toc_all() -> WritingsApiResult<Json<Toc>> {
+    Ok(Json(Writings::toc().as_ref().clone()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{work}",
+    tag = api_tag(),
+    params(("work" = WritingsType, Path, example = "gleaning")),
+    responses(
+        (status = OK, body = Toc, description = "Table of contents of the Work"),
+        (status = NOT_FOUND, description = "Work not available"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn toc_by_work(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((work,)): Path<(WritingsType,)>,
+) -> WritingsApiResult<Json<Toc>> {
+    let toc = match work {
+        WritingsType::HiddenWord => HiddenWord::toc(),
+        WritingsType::Prayer => PrayerParagraph::toc(),
+        WritingsType::Gleaning => GleaningsParagraph::toc(),
+        WritingsType::Meditation => MeditationParagraph::toc(),
+        WritingsType::CDB => CDBParagraph::toc(),
+        WritingsType::Book | WritingsType::Tablet => return Err(WritingsApiError::NotFound),
+    };
+    if toc.children.is_empty() {
+        return Err(WritingsApiError::NotFound);
+    }
+    Ok(Json(toc.as_ref().clone()))
+}
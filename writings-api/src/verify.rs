@@ -0,0 +1,46 @@
+use axum::Json;
+use axum_valid::Validated;
+use serde::Deserialize;
+use utoipa::{OpenApi as DeriveOpenApi, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use validify::Validify;
+use writings::search::{QuoteDiff, QuoteDiffKind, QuoteMatch, verify_quote};
+
+use crate::{WritingsApiError, WritingsApiResult, api_tag};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(QuoteMatch, QuoteDiff, QuoteDiffKind)))]
+pub struct VerifyApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(VerifyApiDoc::openapi()).routes(routes!(post_verify))
+}
+
+#[derive(Debug, Deserialize, Validify, ToSchema)]
+pub struct VerifyRequest {
+    /// The quotation, with or without quotation marks; "…" or "..." marks elided words.
+    #[validate(length(min = 1, max = 10000))]
+    #[schema(example = "Possess a pure, kind and radiant heart…")]
+    pub text: String,
+}
+
+/// Find the source of a quotation and a word-level diff of any discrepancies,
+/// ignoring case, punctuation, quotation marks and diacritics.
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = api_tag(),
+    request_body = VerifyRequest,
+    responses(
+        (status = OK, body = QuoteMatch, description = "The source of the quotation"),
+        (status = NOT_FOUND, description = "No source found"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn post_verify(
+    Validated(Json(request)): Validated<Json<VerifyRequest>>,
+) -> WritingsApiResult<Json<QuoteMatch>> {
+    verify_quote(&request.text)
+        .map(Json)
+        .ok_or(WritingsApiError::NotFound)
+}
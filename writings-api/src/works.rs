@@ -0,0 +1,64 @@
+use axum::{
+    Json,
+    extract::{Path, Query},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi as DeriveOpenApi};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use writings::{Work, WorkInfo};
+
+use crate::{WritingsApiResult, api_tag};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(Work, WorkInfo)))]
+pub struct WorksApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(WorksApiDoc::openapi())
+        .routes(routes!(works_all))
+        .routes(routes!(work))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WorksQuery {
+    /// Only list Works with records embedded in this API.
+    #[serde(default)]
+    #[param(default = false)]
+    pub embedded: bool,
+}
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = api_tag(),
+    params(WorksQuery),
+    responses(
+        (status = OK, body = Vec<WorkInfo>, description = "Catalog of Works"),
+    )
+)]
+pub async fn works_all(Query(query): Query<WorksQuery>) -> WritingsApiResult<Json<Vec<WorkInfo>>> {
+    Ok(Json(
+        WorkInfo::all()
+            .into_iter()
+            .filter(|info| info.embedded || !query.embedded)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{work}",
+    tag = api_tag(),
+    params(("work" = Work, Path, example = "gleanings")),
+    responses(
+        (status = OK, body = WorkInfo, description = "Work metadata"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn work(
+    // MUST be a tuple or it doesn't make it into spec.
+    Path((work,)): Path<(Work,)>,
+) -> WritingsApiResult<Json<WorkInfo>> {
+    Ok(Json(work.info()))
+}
@@ -0,0 +1,91 @@
+use axum::{Json, extract::Query};
+use axum_valid::Validated;
+use serde::Deserialize;
+use serde_json::Value;
+use utoipa::{IntoParams, OpenApi as DeriveOpenApi, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use writings::{Author, HiddenWordKind, PrayerKind, Work, Writings, WritingsType};
+
+use crate::{
+    WritingsApiResult, api_tag,
+    pagination::{Page, PageQuery},
+};
+
+#[derive(DeriveOpenApi)]
+#[openapi(components(schemas(Writings, WritingsType, Author, Work, Kind)))]
+pub struct WritingsListApiDoc;
+
+pub fn router() -> OpenApiRouter {
+    OpenApiRouter::with_openapi(WritingsListApiDoc::openapi()).routes(routes!(writings_list))
+}
+
+/// A prayer kind or Hidden Word kind.
+#[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum Kind {
+    Prayer(PrayerKind),
+    HiddenWord(HiddenWordKind),
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WritingsFilter {
+    #[serde(rename = "type")]
+    #[param(rename = "type")]
+    pub ty: Option<WritingsType>,
+    pub author: Option<Author>,
+    pub work: Option<Work>,
+    #[param(inline)]
+    pub kind: Option<Kind>,
+    /// Prayer sections, separated by `/`, ignoring case and diacritics.
+    #[param(example = "teaching/western")]
+    pub section: Option<String>,
+}
+
+impl WritingsFilter {
+    pub fn query(&self) -> writings::Query {
+        let mut query = writings::Query::new();
+        if let Some(ty) = self.ty {
+            query = query.ty(ty);
+        }
+        if let Some(author) = self.author {
+            query = query.author(author);
+        }
+        if let Some(work) = self.work {
+            query = query.work(work);
+        }
+        query = match self.kind {
+            Some(Kind::Prayer(kind)) => query.prayer_kind(kind),
+            Some(Kind::HiddenWord(kind)) => query.hidden_word_kind(kind),
+            None => query,
+        };
+        match &self.section {
+            Some(section) => section_query(query, section),
+            None => query,
+        }
+    }
+}
+
+/// Add each `/`-separated section of a path, with `-` as spaces, to the query.
+pub fn section_query(query: writings::Query, section: &str) -> writings::Query {
+    section.split('/').fold(query, |query, section| {
+        query.section(&section.replace('-', " "))
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = api_tag(),
+    params(WritingsFilter, PageQuery),
+    responses(
+        (status = OK, body = Page<Writings>, description = "Writings in document order"),
+        (status = BAD_REQUEST, description = "bad request / invalid parameters")
+    )
+)]
+pub async fn writings_list(
+    Query(filter): Query<WritingsFilter>,
+    Validated(Query(page)): Validated<Query<PageQuery>>,
+) -> WritingsApiResult<Json<Page<Value>>> {
+    Ok(Json(page.page::<Writings>(&filter.query())?))
+}
@@ -33,6 +33,12 @@ pub fn derive_writings_trait(input: TokenStream) -> TokenStream {
                 }
             }
 
+            fn work(&self) -> Work {
+                match self {
+                    #(#match_arms.work(),)*
+                }
+            }
+
             fn title(&self) -> String {
                 match self {
                     #(#match_arms.title(),)*
@@ -68,6 +74,12 @@ pub fn derive_writings_trait(input: TokenStream) -> TokenStream {
                     #(#match_arms.text(),)*
                 }
             }
+
+            fn style(&self) -> ParagraphStyle {
+                match self {
+                    #(#match_arms.style(),)*
+                }
+            }
         }
     };
 
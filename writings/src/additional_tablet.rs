@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{WritingsTrait, WritingsType, author::Author};
+use crate::{Work, WritingsTrait, WritingsType, author::Author};
 
 /// TODO: Represent a paragraph from a [`TabletSource`].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -24,6 +24,10 @@ impl WritingsTrait<TabletParagraph> for TabletParagraph {
         self.ref_id.clone()
     }
 
+    fn work(&self) -> Work {
+        Work::from(self.source)
+    }
+
     fn title(&self) -> String {
         self.source.title()
     }
@@ -55,7 +59,7 @@ impl indicium::simple::Indexable for TabletParagraph {
         [
             self.ref_id.as_str(),
             &self.source.to_string(),
-            &diacritics::remove_diacritics(&self.text),
+            &crate::normalize::fold_diacritics(&self.text),
         ]
         .iter()
         .filter_map(|s| {
@@ -70,7 +74,9 @@ impl indicium::simple::Indexable for TabletParagraph {
 }
 
 /// TODO: A work representing additional revealed Tablets.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
 #[cfg_attr(
@@ -79,10 +85,10 @@ impl indicium::simple::Indexable for TabletParagraph {
 )]
 pub enum TabletSource {
     /// from <a target="_blank" href="https://www.bahai.org/library/authoritative-texts/bahaullah/additional-tablets-extracts-from-tablets-revealed-bahaullah/">_Additional Tablets and Extracts from Tablets Revealed by Bahá’u’lláh_</a>
-    #[strum(serialize = "Additional Tablets and Extracts from Tablets Revealed by Bahá'u'lláh")]
+    #[strum(serialize = "Additional Tablets and Extracts from Tablets Revealed by Bahá’u’lláh")]
     // #[cfg_attr(
     //     feature = "poem",
-    //     oai(rename = "Additional Tablets and Extracts from Tablets Revealed by Bahá'u'lláh")
+    //     oai(rename = "Additional Tablets and Extracts from Tablets Revealed by Bahá’u’lláh")
     // )]
     AdditionalTabletsAndExtractsBahaullah,
     /// from <a target="_blank" href="https://www.bahai.org/library/authoritative-texts/abdul-baha/additional-tablets-extracts-talks/">_Additional Tablets, Extracts, and Talks_ by ‘Abdu’l‑Bahá</a>
@@ -96,7 +102,7 @@ pub enum TabletSource {
 
 impl TabletSource {
     pub fn title(&self) -> String {
-        self.to_string()
+        Work::from(*self).title()
     }
 
     pub fn author(&self) -> Author {
@@ -4,7 +4,7 @@ use strum::{Display, EnumIter};
 // TODO: Include `ShoghiEffendi`, The `UniversalHouseOfJustice`, and `Institution()` in _this_ enum?
 
 /// The three Central Figures of the Bahá’í Faith.
-#[derive(Debug, Clone, Copy, EnumIter, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[derive(Debug, Clone, Copy, EnumIter, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
 #[serde(rename_all = "PascalCase")]
 #[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
 #[cfg_attr(
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{WritingsTrait, WritingsType, author::Author};
+use crate::{Work, WritingsTrait, WritingsType, author::Author};
 
 /// TODO: May be used for books that can fit into a single format.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -25,8 +25,12 @@ impl WritingsTrait<BookParagraph> for BookParagraph {
         self.ref_id.clone()
     }
 
+    fn work(&self) -> Work {
+        Work::from(self.title)
+    }
+
     fn title(&self) -> String {
-        self.title.to_string()
+        self.title.title()
     }
 
     fn subtitle(&self) -> Option<String> {
@@ -57,7 +61,7 @@ impl indicium::simple::Indexable for BookParagraph {
             self.ref_id.as_str(),
             &self.title.to_string(),
             self.subtitle.as_deref().unwrap_or_default(),
-            &diacritics::remove_diacritics(&self.text),
+            &crate::normalize::fold_diacritics(&self.text),
         ]
         .iter()
         .filter_map(|s| {
@@ -71,7 +75,9 @@ impl indicium::simple::Indexable for BookParagraph {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
 #[cfg_attr(
@@ -115,10 +121,10 @@ pub enum BookTitle {
     TabletsOfBahaullah,
 
     // ‘Abdu’l‑Bahá
-    #[strum(serialize = "Light of the World: Selected Tablets of ‘Abdu’l-Bahá")]
+    #[strum(serialize = "Light of the World: Selected Tablets of ‘Abdu’l‑Bahá")]
     // #[cfg_attr(
     //     feature = "poem",
-    //     oai(rename = "Light of the World: Selected Tablets of ‘Abdu’l-Bahá")
+    //     oai(rename = "Light of the World: Selected Tablets of ‘Abdu’l‑Bahá")
     // )]
     LightOfTheWorld,
     #[strum(serialize = "Memorials of the Faithful")]
@@ -133,10 +139,10 @@ pub enum BookTitle {
     #[strum(serialize = "The Secret of Divine Civilization")]
     // #[cfg_attr(feature = "poem", oai(rename = "The Secret of Divine Civilization"))]
     SecretOfDivineCivilization,
-    #[strum(serialize = "Selections from the Writings of ‘Abdu’l-Bahá")]
+    #[strum(serialize = "Selections from the Writings of ‘Abdu’l‑Bahá")]
     // #[cfg_attr(
     //     feature = "poem",
-    //     oai(rename = "Selections from the Writings of ‘Abdu’l-Bahá")
+    //     oai(rename = "Selections from the Writings of ‘Abdu’l‑Bahá")
     // )]
     SelectionsFromTheWritingsOfAbdulBaha,
     #[strum(serialize = "Some Answered Questions")]
@@ -167,7 +173,7 @@ pub enum BookTitle {
 
 impl BookTitle {
     pub fn title(&self) -> String {
-        self.to_string()
+        Work::from(*self).title()
     }
 
     pub fn author(&self) -> Author {
@@ -185,7 +191,7 @@ impl BookTitle {
             BookTitle::TabernacleOfUnity => Author::Bahaullah,
             BookTitle::TabletsOfBahaullah => Author::Bahaullah,
 
-            // ‘Abdu’l-Bahá
+            // ‘Abdu’l‑Bahá
             BookTitle::LightOfTheWorld => Author::AbdulBaha,
             BookTitle::MemorialsOfTheFaithful => Author::AbdulBaha,
             BookTitle::ParisTalks => Author::AbdulBaha,
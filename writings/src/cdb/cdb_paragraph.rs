@@ -1,5 +1,5 @@
 use crate::{
-    Citation, WritingsTrait, WritingsType, author::Author, paragraph_style::ParagraphStyle,
+    Citation, Work, WritingsTrait, WritingsType, author::Author, paragraph_style::ParagraphStyle,
 };
 use serde::{Deserialize, Serialize};
 
@@ -40,6 +40,10 @@ impl WritingsTrait<CDBParagraph> for CDBParagraph {
         self.ref_id.clone()
     }
 
+    fn work(&self) -> Work {
+        Work::CallOfTheDivineBeloved
+    }
+
     fn title(&self) -> String {
         self.work_title.clone()
     }
@@ -63,6 +67,10 @@ impl WritingsTrait<CDBParagraph> for CDBParagraph {
     fn text(&self) -> String {
         self.text.clone()
     }
+
+    fn style(&self) -> ParagraphStyle {
+        self.style
+    }
 }
 
 #[cfg(feature = "indicium")]
@@ -74,7 +82,7 @@ impl indicium::simple::Indexable for CDBParagraph {
                 if s.is_empty() {
                     None
                 } else {
-                    Some(diacritics::remove_diacritics(s))
+                    Some(crate::normalize::fold_diacritics(s).into_owned())
                 }
             })
             .collect::<Vec<_>>()
@@ -1,7 +1,7 @@
 #![cfg(feature = "_visitors")]
 use super::CDBParagraph;
 use crate::{
-    Citation, ParagraphStyle,
+    Citation, ParagraphStyle, Toc, Work,
     scraper_ext::{ClassList, ElementExt},
     writings_visitor::{CitationText, VisitorAction, WritingsVisitor},
 };
@@ -39,6 +39,14 @@ impl WritingsVisitor for CDBVisitor {
         &self.paragraphs
     }
 
+    fn get_toc(&self) -> Toc {
+        let mut toc = Toc::new(Work::CallOfTheDivineBeloved.title());
+        for paragraph in &self.paragraphs {
+            toc.insert(&[(paragraph.work_title.clone(), None)], &paragraph.ref_id);
+        }
+        toc
+    }
+
     fn visit(&mut self, element: &scraper::ElementRef, _level: usize) -> VisitorAction {
         let name = element.name();
 
@@ -0,0 +1,320 @@
+//! Splitting the Writings into overlapping chunks of about the same size for retrieval
+//! (embedding) pipelines, at paragraph and sentence boundaries.
+
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Author, Sentence, Work, Writings, WritingsResult, WritingsTrait as _};
+
+/// The approximate number of characters per token of English text.
+pub const CHARS_PER_TOKEN: usize = 4;
+
+/// The size of a chunk, in characters or approximate tokens (see [`CHARS_PER_TOKEN`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Union))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum ChunkSize {
+    Chars(usize),
+    Tokens(usize),
+}
+
+impl ChunkSize {
+    /// The size in characters.
+    pub fn chars(&self) -> usize {
+        match self {
+            ChunkSize::Chars(chars) => *chars,
+            ChunkSize::Tokens(tokens) => tokens * CHARS_PER_TOKEN,
+        }
+    }
+}
+
+/// How to split the Writings into chunks with [`chunk()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ChunkOptions {
+    /// The maximum size of a chunk; only a single sentence longer than this is a larger chunk.
+    pub size: ChunkSize,
+
+    /// The maximum size of the sentences at the end of a chunk repeated at the start of the
+    /// next, if the chunks are of the same selection (prayer, Gleaning, etc.).
+    pub overlap: ChunkSize,
+}
+
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        Self {
+            size: ChunkSize::Tokens(256),
+            overlap: ChunkSize::Tokens(32),
+        }
+    }
+}
+
+/// Consecutive sentences of the Writings, from [`chunk()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Chunk {
+    /// The address of the first sentence of the chunk (see [`Sentence::address`]),
+    /// unique among the chunks of the same Writings.
+    pub id: String,
+
+    /// The text of the chunk, with a blank line between paragraphs.
+    pub text: String,
+
+    /// The reference IDs of the paragraphs of the chunk, in order.
+    pub ref_ids: Vec<String>,
+
+    /// The part of each paragraph in the chunk, in order.
+    pub ranges: Vec<ChunkRange>,
+
+    pub work: Work,
+
+    pub author: Author,
+
+    /// The number of characters of the `text`.
+    pub chars: usize,
+
+    /// The approximate number of tokens of the `text` (see [`CHARS_PER_TOKEN`]).
+    pub tokens: usize,
+}
+
+/// The part of a paragraph in a [`Chunk`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ChunkRange {
+    /// The reference ID of the paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    /// The `char` offset of the part in the `text` of the paragraph.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the part.
+    pub end: usize,
+}
+
+/// Split the Writings, in order, into chunks of up to `options.size`, each within a single
+/// selection (prayer, Gleaning, Hidden Word, etc.) and of whole sentences, ending at the end
+/// of a paragraph where that leaves the chunk at least half full.
+///
+/// ```
+/// use writings::{ChunkOptions, ChunkSize, Query, Work, chunk};
+///
+/// let options = ChunkOptions {
+///     size: ChunkSize::Chars(1000),
+///     overlap: ChunkSize::Chars(200),
+/// };
+/// let chunks = chunk(&Query::new().work(Work::Gleanings).execute(), &options);
+///
+/// assert!(chunks.iter().all(|c| c.work == Work::Gleanings));
+/// assert!(chunks.iter().all(|c| c.chars <= 1000 || c.ranges.len() == 1));
+/// assert!(chunks[0].id.ends_with("#s1"));
+/// ```
+pub fn chunk<'a>(
+    writings: impl IntoIterator<Item = &'a Writings>,
+    options: &ChunkOptions,
+) -> Vec<Chunk> {
+    let mut chunks = vec![];
+    let mut selection: Vec<&Writings> = vec![];
+    for w in writings {
+        if selection
+            .last()
+            .is_some_and(|last| !is_same_selection(last, w))
+        {
+            chunk_selection(&selection, options, &mut chunks);
+            selection.clear();
+        }
+        selection.push(w);
+    }
+    chunk_selection(&selection, options, &mut chunks);
+    chunks
+}
+
+/// Write the chunks as JSON Lines: one JSON object per line.
+pub fn write_jsonl<'a>(
+    chunks: impl IntoIterator<Item = &'a Chunk>,
+    mut writer: impl Write,
+) -> WritingsResult<()> {
+    for chunk in chunks {
+        serde_json::to_writer(&mut writer, chunk)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn is_same_selection(a: &Writings, b: &Writings) -> bool {
+    a.work() == b.work()
+        && a.title() == b.title()
+        && a.subtitle() == b.subtitle()
+        && a.number() == b.number()
+}
+
+/// A sentence of a paragraph of a selection.
+struct Unit<'a> {
+    paragraph: &'a Writings,
+    sentence: Sentence,
+    chars: usize,
+    /// Whether this is the last sentence of the paragraph.
+    last: bool,
+}
+
+fn chunk_selection(selection: &[&Writings], options: &ChunkOptions, chunks: &mut Vec<Chunk>) {
+    let units = selection
+        .iter()
+        .flat_map(|&paragraph| {
+            let sentences = paragraph.sentences();
+            let count = sentences.len();
+            sentences
+                .into_iter()
+                .enumerate()
+                .map(move |(i, sentence)| Unit {
+                    paragraph,
+                    chars: sentence.end - sentence.start,
+                    sentence,
+                    last: i + 1 == count,
+                })
+        })
+        .collect::<Vec<_>>();
+    // The size of the units, with a space or blank line between them.
+    let size = |units: &[Unit]| {
+        units.iter().map(|u| u.chars).sum::<usize>()
+            + units
+                .windows(2)
+                .map(|w| if w[0].last { 2 } else { 1 })
+                .sum::<usize>()
+    };
+    let (max, overlap) = (options.size.chars(), options.overlap.chars());
+
+    let mut start = 0;
+    while start < units.len() {
+        let mut end = start + 1;
+        while end < units.len() && size(&units[start..=end]) <= max {
+            end += 1;
+        }
+        if end < units.len()
+            && !units[end - 1].last
+            && let Some(e) = (start + 1..end)
+                .rev()
+                .find(|&e| units[e - 1].last && size(&units[start..e]) * 2 >= max)
+        {
+            end = e;
+        }
+        chunks.push(new_chunk(&units[start..end]));
+        if end == units.len() {
+            break;
+        }
+
+        let mut next = end;
+        while next - 1 > start && size(&units[next - 1..end]) <= overlap {
+            next -= 1;
+        }
+        start = next;
+    }
+}
+
+fn new_chunk(units: &[Unit]) -> Chunk {
+    let mut parts: Vec<(&Writings, ChunkRange)> = vec![];
+    for unit in units {
+        match parts.last_mut() {
+            Some((paragraph, range)) if std::ptr::eq(*paragraph, unit.paragraph) => {
+                range.end = unit.sentence.end
+            }
+            _ => parts.push((
+                unit.paragraph,
+                ChunkRange {
+                    ref_id: unit.paragraph.ref_id(),
+                    start: unit.sentence.start,
+                    end: unit.sentence.end,
+                },
+            )),
+        }
+    }
+    let text = parts
+        .iter()
+        .map(|(paragraph, range)| {
+            paragraph
+                .text()
+                .chars()
+                .skip(range.start)
+                .take(range.end - range.start)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let chars = text.chars().count();
+    let ranges = parts
+        .into_iter()
+        .map(|(_, range)| range)
+        .collect::<Vec<_>>();
+    Chunk {
+        id: units[0].sentence.address.clone(),
+        ref_ids: ranges.iter().map(|r| r.ref_id.clone()).collect(),
+        ranges,
+        work: units[0].paragraph.work(),
+        author: units[0].paragraph.author(),
+        chars,
+        tokens: chars.div_ceil(CHARS_PER_TOKEN),
+        text,
+    }
+}
+
+#[cfg(all(test, feature = "embed-all"))]
+mod tests {
+    use super::*;
+    use crate::{EmbedAllTrait as _, Query};
+
+    #[test]
+    fn test_chunk() {
+        let options = ChunkOptions {
+            size: ChunkSize::Tokens(100),
+            overlap: ChunkSize::Tokens(25),
+        };
+        let writings = Query::new().work(Work::PrayersAndMeditations).execute();
+        let chunks = chunk(&writings, &options);
+        assert!(chunks.len() > writings.len() / 2);
+
+        let all = Writings::all_map();
+        for chunk in &chunks {
+            assert!(chunk.tokens <= 100 || chunk.ranges.len() == 1, "{chunk:?}");
+            assert_eq!(chunk.ref_ids.len(), chunk.ranges.len());
+            let texts = chunk
+                .ranges
+                .iter()
+                .map(|range| {
+                    all[&range.ref_id]
+                        .text()
+                        .chars()
+                        .skip(range.start)
+                        .take(range.end - range.start)
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(chunk.text, texts.join("\n\n"));
+        }
+
+        // Consecutive chunks of the same prayer overlap.
+        let overlapping = chunks.windows(2).filter(|w| {
+            let (a, b) = (w[0].ranges.last().unwrap(), &w[1].ranges[0]);
+            a.ref_id == b.ref_id && b.start < a.end
+        });
+        assert!(overlapping.count() > 0);
+        assert!(chunks.windows(2).all(|w| w[0].id != w[1].id));
+
+        let mut jsonl = vec![];
+        write_jsonl(&chunks[..2], &mut jsonl).unwrap();
+        let lines = String::from_utf8(jsonl).unwrap();
+        assert_eq!(lines.lines().count(), 2);
+        assert_eq!(
+            serde_json::from_str::<Chunk>(lines.lines().next().unwrap()).unwrap(),
+            chunks[0]
+        );
+    }
+}
@@ -6,8 +6,8 @@ use std::{
 };
 
 use crate::{
-    CDBParagraph, GleaningsParagraph, HiddenWord, MeditationParagraph, PrayerParagraph, Writings,
-    WritingsTrait,
+    CDBParagraph, GleaningsParagraph, HiddenWord, MeditationParagraph, PrayerParagraph, Toc,
+    Writings, WritingsTrait,
     writings_visitor::{VisitorAction, WritingsVisitor},
 };
 
@@ -17,6 +17,108 @@ pub trait EmbedAllTrait<T: EmbedAllTrait<T> + WritingsTrait<T>>: WritingsTrait<T
 
     /// Like `get_all()`, mapped with each record's `ref_id` as the key.
     fn all_map() -> Arc<HashMap<String, Self>>;
+
+    /// Each record's `ref_id` mapped to its position in `all()`, which is canonical document order.
+    fn all_index() -> Arc<HashMap<String, usize>>;
+
+    /// The table of contents of [`Self`], built while parsing the embedded HTML.
+    fn toc() -> Arc<Toc>;
+
+    /// The position of this record in `all()`, if it is one of the embedded records.
+    fn position(&self) -> Option<usize> {
+        let start = *Self::all_index().get(&self.ref_id())?;
+        position_from(&Self::all(), start, self)
+    }
+
+    /// The previous record in document order within the same Work.
+    fn prev(&self) -> Option<Self> {
+        let position = self.position()?.checked_sub(1)?;
+        Self::all()
+            .get(position)
+            .filter(|it| is_same_work(*it, self))
+            .cloned()
+    }
+
+    /// The next record in document order within the same Work.
+    fn next(&self) -> Option<Self> {
+        let position = self.position()? + 1;
+        Self::all()
+            .get(position)
+            .filter(|it| is_same_work(*it, self))
+            .cloned()
+    }
+
+    /// All records of the same selection (e.g. all paragraphs of a Gleaning or prayer),
+    /// including this one, in document order.
+    fn siblings(&self) -> Vec<Self> {
+        let Some(position) = self.position() else {
+            return vec![];
+        };
+        let all = Self::all();
+        let start = all[..position]
+            .iter()
+            .rposition(|it| !is_same_selection(it, self))
+            .map_or(0, |i| i + 1);
+        let end = all[position..]
+            .iter()
+            .position(|it| !is_same_selection(it, self))
+            .map_or(all.len(), |i| position + i);
+        all[start..end].to_vec()
+    }
+
+    /// Up to `n` records before and after this one within the same Work,
+    /// including this one, in document order.
+    fn context(&self, n: usize) -> Vec<Self> {
+        let Some(position) = self.position() else {
+            return vec![];
+        };
+        let all = Self::all();
+        let start = (position.saturating_sub(n)..position)
+            .find(|&i| is_same_work(&all[i], self))
+            .unwrap_or(position);
+        let end = (position + 1..all.len().min(position + n + 1))
+            .take_while(|&i| is_same_work(&all[i], self))
+            .last()
+            .unwrap_or(position);
+        all[start..=end].to_vec()
+    }
+}
+
+fn is_same_work<W: WritingsTrait<T>, T: WritingsTrait<T>>(a: &W, b: &W) -> bool {
+    a.ty() == b.ty() && a.title() == b.title()
+}
+
+fn is_same_selection<W: WritingsTrait<T>, T: WritingsTrait<T>>(a: &W, b: &W) -> bool {
+    is_same_work(a, b) && a.subtitle() == b.subtitle() && a.number() == b.number()
+}
+
+/// The position of `it` in `all`, from the position of the first record with its `ref_id`.
+/// Some records share a `ref_id`, e.g. poetry split out of a paragraph.
+fn position_from<T: PartialEq>(all: &[T], start: usize, it: &T) -> Option<usize> {
+    all.get(start..)?
+        .iter()
+        .position(|other| other == it)
+        .map(|offset| start + offset)
+}
+
+/// Map each `ref_id` to its last record.
+fn map_ref_ids<T: WritingsTrait<T>>(all: &[T]) -> HashMap<String, T> {
+    all.iter().map(|it| (it.ref_id(), it.clone())).collect()
+}
+
+/// Map each `ref_id` to the position of its first record.
+fn index_ref_ids<T: WritingsTrait<T>>(all: &[T]) -> HashMap<String, usize> {
+    let mut index = HashMap::with_capacity(all.len());
+    for (position, it) in all.iter().enumerate() {
+        index.entry(it.ref_id()).or_insert(position);
+    }
+    index
+}
+
+/// The records parsed from the embedded HTML, with their table of contents.
+struct Parsed<T> {
+    all: Arc<Vec<T>>,
+    toc: Arc<Toc>,
 }
 
 trait Storage: WritingsTrait<Self> {
@@ -26,11 +128,37 @@ trait Storage: WritingsTrait<Self> {
     /// The visitor used to parse the HTML.
     type Visitor: WritingsVisitor<Writings = Self>;
 
-    /// The static cache for all records.
-    fn once_all() -> &'static OnceLock<Arc<Vec<Self>>>;
+    /// The static cache for all records and their table of contents.
+    fn once_parsed() -> &'static OnceLock<Parsed<Self>>;
 
     /// The static cache for all records mapped by `ref_id`.
     fn once_all_map() -> &'static OnceLock<Arc<HashMap<String, Self>>>;
+
+    /// The static cache for the positions of all records mapped by `ref_id`.
+    fn once_all_index() -> &'static OnceLock<Arc<HashMap<String, usize>>>;
+}
+
+/// A fingerprint of the embedded HTML of all Writings, which changes whenever the corpus
+/// does, without parsing it.
+#[cfg(feature = "search")]
+pub(crate) fn corpus_fingerprint() -> u64 {
+    let html = [
+        <CDBParagraph as Storage>::HTML,
+        <HiddenWord as Storage>::HTML,
+        <PrayerParagraph as Storage>::HTML,
+        <GleaningsParagraph as Storage>::HTML,
+        <MeditationParagraph as Storage>::HTML,
+    ];
+    fnv1a(html.iter().flat_map(|html| html.bytes()))
+}
+
+/// The FNV-1a hash of the bytes, for fingerprints that are stable across Rust versions,
+/// unlike `std::hash`.
+#[cfg(feature = "search")]
+pub(crate) fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes.into_iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
 }
 
 /// We use a marker trait for single blanket exception until negative trait bounds are stable.
@@ -47,81 +175,91 @@ where
     T: 'static + WritingsTrait<Self> + Storage + NotWritingsEnum,
 {
     fn all() -> Arc<Vec<Self>> {
-        Self::once_all()
-            .get_or_init(|| {
-                let mut visitor = T::Visitor::default();
-                visitor.parse_and_traverse(T::HTML);
-                Arc::new(visitor.get_visited().to_vec())
-            })
-            .clone()
+        Self::once_parsed().get_or_init(parse::<T>).all.clone()
     }
 
     fn all_map() -> Arc<HashMap<String, Self>> {
         Self::once_all_map()
-            .get_or_init(|| {
-                let mut visitor = T::Visitor::default();
-                visitor.parse_and_traverse(T::HTML);
-                Arc::new(
-                    visitor
-                        .get_visited()
-                        .iter()
-                        .map(|it| (it.ref_id(), it.clone()))
-                        .collect::<HashMap<_, _>>(),
-                )
-            })
+            .get_or_init(|| Arc::new(map_ref_ids(&Self::all())))
             .clone()
     }
+
+    fn all_index() -> Arc<HashMap<String, usize>> {
+        Self::once_all_index()
+            .get_or_init(|| Arc::new(index_ref_ids(&Self::all())))
+            .clone()
+    }
+
+    fn toc() -> Arc<Toc> {
+        Self::once_parsed().get_or_init(parse::<T>).toc.clone()
+    }
+}
+
+/// Parse the embedded HTML once for both the records and the table of contents.
+fn parse<T: Storage>() -> Parsed<T> {
+    let mut visitor = T::Visitor::default();
+    visitor.parse_and_traverse(T::HTML);
+    Parsed {
+        all: Arc::new(visitor.get_visited().to_vec()),
+        toc: Arc::new(visitor.get_toc()),
+    }
 }
 
 #[cfg(feature = "embed-all")]
 impl EmbedAllTrait<Writings> for Writings {
     fn all() -> Arc<Vec<Self>> {
-        Self::once_all()
-            .get_or_init(|| {
-                use crate::CDBParagraph;
-
-                let mut all = vec![];
-                #[cfg(feature = "embed-hidden-words")]
-                all.extend(
-                    HiddenWord::all()
-                        .iter()
-                        .map(|it| Writings::HiddenWord(it.clone())),
-                );
-                #[cfg(feature = "embed-prayers")]
-                all.extend(
-                    PrayerParagraph::all()
-                        .iter()
-                        .map(|it| Writings::Prayer(it.clone())),
-                );
-                #[cfg(feature = "embed-gleanings")]
-                all.extend(
-                    GleaningsParagraph::all()
-                        .iter()
-                        .map(|it| Writings::Gleaning(it.clone())),
-                );
-                #[cfg(feature = "embed-cdb")]
-                all.extend(
-                    CDBParagraph::all()
-                        .iter()
-                        .map(|it| Writings::CDB(it.clone())),
-                );
-                Arc::new(all)
-            })
-            .clone()
+        Self::once_parsed().get_or_init(parse_writings).all.clone()
     }
 
     fn all_map() -> Arc<HashMap<String, Self>> {
         Self::once_all_map()
-            .get_or_init(|| {
-                Arc::new(
-                    Self::all()
-                        .iter()
-                        .map(|it| (it.ref_id(), it.clone()))
-                        .collect::<HashMap<_, _>>(),
-                )
-            })
+            .get_or_init(|| Arc::new(map_ref_ids(&Self::all())))
+            .clone()
+    }
+
+    fn all_index() -> Arc<HashMap<String, usize>> {
+        Self::once_all_index()
+            .get_or_init(|| Arc::new(index_ref_ids(&Self::all())))
             .clone()
     }
+
+    fn toc() -> Arc<Toc> {
+        Self::once_parsed().get_or_init(parse_writings).toc.clone()
+    }
+}
+
+/// All types of Writings, with a table of contents entry for each Work.
+#[cfg(feature = "embed-all")]
+fn parse_writings() -> Parsed<Writings> {
+    fn add<T: EmbedAllTrait<T> + WritingsTrait<T>>(
+        all: &mut Vec<Writings>,
+        toc: &mut Toc,
+        wrap: fn(T) -> Writings,
+    ) {
+        all.extend(T::all().iter().cloned().map(wrap));
+        toc.children.push(T::toc().as_ref().clone());
+    }
+
+    let mut all = vec![];
+    let mut toc = Toc::new("Writings");
+    #[cfg(feature = "embed-hidden-words")]
+    add(&mut all, &mut toc, Writings::HiddenWord);
+    #[cfg(feature = "embed-prayers")]
+    add(&mut all, &mut toc, Writings::Prayer);
+    #[cfg(feature = "embed-gleanings")]
+    add(&mut all, &mut toc, Writings::Gleaning);
+    #[cfg(feature = "embed-meditations")]
+    add(&mut all, &mut toc, Writings::Meditation);
+    #[cfg(feature = "embed-cdb")]
+    add(&mut all, &mut toc, Writings::CDB);
+
+    toc.children.retain(|c| c.paragraphs > 0);
+    toc.paragraphs = toc.children.iter().map(|c| c.paragraphs).sum();
+    toc.ref_id = toc.children.iter().find_map(|c| c.ref_id.clone());
+    Parsed {
+        all: Arc::new(all),
+        toc: Arc::new(toc),
+    }
 }
 
 #[cfg(feature = "embed-all")]
@@ -130,15 +268,20 @@ impl Storage for Writings {
 
     const HTML: &str = "";
 
-    fn once_all() -> &'static OnceLock<Arc<Vec<Self>>> {
-        static ALL: OnceLock<Arc<Vec<Writings>>> = OnceLock::new();
-        &ALL
+    fn once_parsed() -> &'static OnceLock<Parsed<Self>> {
+        static PARSED: OnceLock<Parsed<Writings>> = OnceLock::new();
+        &PARSED
     }
 
     fn once_all_map() -> &'static OnceLock<Arc<HashMap<String, Self>>> {
         static ALL_MAP: OnceLock<Arc<HashMap<String, Writings>>> = OnceLock::new();
         &ALL_MAP
     }
+
+    fn once_all_index() -> &'static OnceLock<Arc<HashMap<String, usize>>> {
+        static ALL_INDEX: OnceLock<Arc<HashMap<String, usize>>> = OnceLock::new();
+        &ALL_INDEX
+    }
 }
 
 #[cfg(feature = "embed-cdb")]
@@ -147,15 +290,20 @@ impl Storage for CDBParagraph {
     // const HTML: &str = include_str!("../html/call_divine_beloved.html");
     const HTML: &str = "";
 
-    fn once_all() -> &'static OnceLock<Arc<Vec<Self>>> {
-        static ALL: OnceLock<Arc<Vec<CDBParagraph>>> = OnceLock::new();
-        &ALL
+    fn once_parsed() -> &'static OnceLock<Parsed<Self>> {
+        static PARSED: OnceLock<Parsed<CDBParagraph>> = OnceLock::new();
+        &PARSED
     }
 
     fn once_all_map() -> &'static OnceLock<Arc<HashMap<String, Self>>> {
         static ALL_MAP: OnceLock<Arc<HashMap<String, CDBParagraph>>> = OnceLock::new();
         &ALL_MAP
     }
+
+    fn once_all_index() -> &'static OnceLock<Arc<HashMap<String, usize>>> {
+        static ALL_INDEX: OnceLock<Arc<HashMap<String, usize>>> = OnceLock::new();
+        &ALL_INDEX
+    }
 }
 
 #[cfg(feature = "embed-hidden-words")]
@@ -164,15 +312,20 @@ impl Storage for HiddenWord {
 
     const HTML: &str = include_str!("../html/hidden_words.html");
 
-    fn once_all() -> &'static OnceLock<Arc<Vec<Self>>> {
-        static ALL: OnceLock<Arc<Vec<HiddenWord>>> = OnceLock::new();
-        &ALL
+    fn once_parsed() -> &'static OnceLock<Parsed<Self>> {
+        static PARSED: OnceLock<Parsed<HiddenWord>> = OnceLock::new();
+        &PARSED
     }
 
     fn once_all_map() -> &'static OnceLock<Arc<HashMap<String, Self>>> {
         static ALL_MAP: OnceLock<Arc<HashMap<String, HiddenWord>>> = OnceLock::new();
         &ALL_MAP
     }
+
+    fn once_all_index() -> &'static OnceLock<Arc<HashMap<String, usize>>> {
+        static ALL_INDEX: OnceLock<Arc<HashMap<String, usize>>> = OnceLock::new();
+        &ALL_INDEX
+    }
 }
 
 #[cfg(feature = "embed-prayers")]
@@ -181,15 +334,20 @@ impl Storage for PrayerParagraph {
 
     const HTML: &str = include_str!("../html/prayers.html");
 
-    fn once_all() -> &'static OnceLock<Arc<Vec<Self>>> {
-        static ALL: OnceLock<Arc<Vec<PrayerParagraph>>> = OnceLock::new();
-        &ALL
+    fn once_parsed() -> &'static OnceLock<Parsed<Self>> {
+        static PARSED: OnceLock<Parsed<PrayerParagraph>> = OnceLock::new();
+        &PARSED
     }
 
     fn once_all_map() -> &'static OnceLock<Arc<HashMap<String, Self>>> {
         static ALL_MAP: OnceLock<Arc<HashMap<String, PrayerParagraph>>> = OnceLock::new();
         &ALL_MAP
     }
+
+    fn once_all_index() -> &'static OnceLock<Arc<HashMap<String, usize>>> {
+        static ALL_INDEX: OnceLock<Arc<HashMap<String, usize>>> = OnceLock::new();
+        &ALL_INDEX
+    }
 }
 
 #[cfg(feature = "embed-gleanings")]
@@ -198,15 +356,20 @@ impl Storage for GleaningsParagraph {
 
     const HTML: &str = include_str!("../html/gleanings.html");
 
-    fn once_all() -> &'static OnceLock<Arc<Vec<Self>>> {
-        static ALL: OnceLock<Arc<Vec<GleaningsParagraph>>> = OnceLock::new();
-        &ALL
+    fn once_parsed() -> &'static OnceLock<Parsed<Self>> {
+        static PARSED: OnceLock<Parsed<GleaningsParagraph>> = OnceLock::new();
+        &PARSED
     }
 
     fn once_all_map() -> &'static OnceLock<Arc<HashMap<String, Self>>> {
         static ALL_MAP: OnceLock<Arc<HashMap<String, GleaningsParagraph>>> = OnceLock::new();
         &ALL_MAP
     }
+
+    fn once_all_index() -> &'static OnceLock<Arc<HashMap<String, usize>>> {
+        static ALL_INDEX: OnceLock<Arc<HashMap<String, usize>>> = OnceLock::new();
+        &ALL_INDEX
+    }
 }
 
 #[cfg(feature = "embed-meditations")]
@@ -215,15 +378,20 @@ impl Storage for MeditationParagraph {
 
     const HTML: &str = include_str!("../html/meditations.html");
 
-    fn once_all() -> &'static OnceLock<Arc<Vec<Self>>> {
-        static ALL: OnceLock<Arc<Vec<MeditationParagraph>>> = OnceLock::new();
-        &ALL
+    fn once_parsed() -> &'static OnceLock<Parsed<Self>> {
+        static PARSED: OnceLock<Parsed<MeditationParagraph>> = OnceLock::new();
+        &PARSED
     }
 
     fn once_all_map() -> &'static OnceLock<Arc<HashMap<String, Self>>> {
         static ALL_MAP: OnceLock<Arc<HashMap<String, MeditationParagraph>>> = OnceLock::new();
         &ALL_MAP
     }
+
+    fn once_all_index() -> &'static OnceLock<Arc<HashMap<String, usize>>> {
+        static ALL_INDEX: OnceLock<Arc<HashMap<String, usize>>> = OnceLock::new();
+        &ALL_INDEX
+    }
 }
 
 /// A no-op visitor for the `Writings` enum, which just aggregates the other types.
@@ -240,7 +408,107 @@ impl WritingsVisitor for NoOpVisitorForWritingsEnum {
         &[]
     }
 
+    fn get_toc(&self) -> Toc {
+        Toc::new("")
+    }
+
     fn visit(&mut self, _element: &scraper::ElementRef, _level: usize) -> VisitorAction {
         VisitorAction::Stop
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hidden_word(ref_id: &str, text: &str) -> HiddenWord {
+        HiddenWord {
+            ref_id: ref_id.to_string(),
+            kind: crate::HiddenWordKind::Arabic,
+            number: Some(1),
+            prelude: None,
+            invocation: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_position_from() {
+        let all = [
+            hidden_word("1", "a"),
+            hidden_word("2", "b"),
+            hidden_word("2", "c"),
+            hidden_word("3", "d"),
+        ];
+        let index = index_ref_ids(&all);
+        assert_eq!(index["2"], 1);
+        for (position, it) in all.iter().enumerate() {
+            assert_eq!(position_from(&all, index[&it.ref_id], it), Some(position));
+        }
+        assert_eq!(position_from(&all, 3, &all[1]), None);
+        assert_eq!(position_from(&all, 9, &all[1]), None);
+    }
+
+    #[cfg(feature = "embed-all")]
+    #[test]
+    fn test_navigation() {
+        let all = Writings::all();
+        let (first, last) = (&all[0], &all[all.len() - 1]);
+        assert_eq!(first.position(), Some(0));
+        assert_eq!(first.prev(), None);
+        assert_eq!(first.next().as_ref(), Some(&all[1]));
+        assert_eq!(first.context(2), all[..3]);
+        assert_eq!(last.position(), Some(all.len() - 1));
+        assert_eq!(last.next(), None);
+        assert_eq!(last.context(2), all[all.len() - 3..]);
+
+        // Navigation stops at the boundaries between Works.
+        let boundary = (1..all.len())
+            .find(|&i| all[i].work() != all[i - 1].work())
+            .unwrap();
+        let (end, start) = (&all[boundary - 1], &all[boundary]);
+        assert_eq!(end.next(), None);
+        assert_eq!(start.prev(), None);
+        assert_eq!(end.context(3), all[boundary - 4..boundary]);
+        assert_eq!(start.context(3), all[boundary..boundary + 4]);
+        assert_eq!(all[boundary + 1].context(3), all[boundary..boundary + 5]);
+
+        let gleaning = all
+            .iter()
+            .find(|w| matches!(w, Writings::Gleaning(g) if g.number == 19 && g.paragraph == 2))
+            .unwrap();
+        let siblings = gleaning.siblings();
+        assert!(siblings.contains(gleaning));
+        assert!(siblings.iter().all(|w| w.number() == Some(19)));
+        assert_ne!(siblings[0].prev().and_then(|w| w.number()), Some(19));
+        assert_ne!(
+            siblings[siblings.len() - 1].next().and_then(|w| w.number()),
+            Some(19)
+        );
+        assert!(hidden_word("0", "").siblings().is_empty());
+        assert!(hidden_word("0", "").context(3).is_empty());
+    }
+
+    #[cfg(feature = "embed-all")]
+    #[test]
+    fn test_all_and_toc() {
+        // `Writings::all()` and `Writings::toc()` cover the same Works.
+        let all = Writings::all();
+        let toc = Writings::toc();
+        assert_eq!(toc.paragraphs as usize, all.len());
+        assert_eq!(
+            MeditationParagraph::toc().paragraphs as usize,
+            MeditationParagraph::all().len()
+        );
+        let meditations = all
+            .iter()
+            .filter(|w| matches!(w, Writings::Meditation(_)))
+            .count();
+        assert_eq!(meditations, MeditationParagraph::all().len());
+        assert!(
+            toc.children
+                .iter()
+                .any(|c| c.ref_id == MeditationParagraph::toc().ref_id)
+        );
+    }
+}
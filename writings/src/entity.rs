@@ -0,0 +1,259 @@
+#![cfg(feature = "embed-all")]
+//! A gazetteer of the persons (e.g. Moses, Ṭáhirih) and places (e.g. Carmel, Baghdád, ‘Akká)
+//! mentioned in the Writings, with their variant spellings, and their mentions in any text
+//! and in all embedded Writings.
+
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::text::{Phrases, fold};
+use crate::{EmbedAllTrait as _, Work, Writings, WritingsTrait as _};
+
+/// The gazetteer, in alphabetical order of `id`.
+static ENTITIES: LazyLock<Vec<Entity>> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("entities.json"))
+        .unwrap_or_else(|err| panic!("Invalid entities.json: {err}"))
+});
+
+/// The spellings of all entities, with the position of the entity in the gazetteer.
+static SPELLINGS: LazyLock<Phrases> = LazyLock::new(|| {
+    Phrases::new(
+        ENTITIES
+            .iter()
+            .enumerate()
+            .flat_map(|(position, entity)| entity.spellings().map(move |s| (s, position))),
+    )
+});
+
+/// The mentions of each entity in all embedded Writings, by position in the gazetteer.
+static MENTIONS: LazyLock<Vec<Vec<Mention>>> = LazyLock::new(|| {
+    let mut mentions = vec![vec![]; ENTITIES.len()];
+    for w in Writings::all().iter() {
+        let text = w.text();
+        for (range, position) in SPELLINGS.find_in(&text, true) {
+            let found = EntityMatch::new(&text, range, &ENTITIES[position]);
+            mentions[position].push(Mention {
+                id: found.id,
+                ref_id: w.ref_id(),
+                work: w.work(),
+                text: found.text,
+                start: found.start,
+                end: found.end,
+            });
+        }
+    }
+    mentions
+});
+
+/// Whether an [`Entity`] is a person or a place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum EntityKind {
+    Person,
+    Place,
+}
+
+/// A person or place of the gazetteer, see [`Entity::all()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Entity {
+    /// The name in lowercase ASCII, e.g. "akka".
+    pub id: String,
+
+    /// The name as transliterated in the Writings, e.g. "‘Akká".
+    pub name: String,
+
+    pub kind: EntityKind,
+
+    /// Other names and spellings, e.g. "Acre". Spellings that differ only in case, typography
+    /// or diacritics (see [`crate::normalize::fold()`]) match anyway.
+    pub variants: Vec<String>,
+
+    /// Who or what the entity is, in a sentence.
+    pub description: String,
+}
+
+impl Entity {
+    /// All persons and places of the gazetteer, in alphabetical order of `id`.
+    pub fn all() -> &'static [Entity] {
+        &ENTITIES
+    }
+
+    /// Find an entity by its `id`, or by its `name` or one of its `variants`, ignoring case,
+    /// typography and diacritics.
+    ///
+    /// ```
+    /// use writings::{Entity, EntityKind};
+    ///
+    /// let carmel = Entity::find("Carmel").unwrap();
+    /// assert_eq!(carmel.name, "Mount Carmel");
+    /// assert_eq!(carmel.kind, EntityKind::Place);
+    /// assert_eq!(Entity::find("Tahirih").unwrap().id, "tahirih");
+    /// ```
+    pub fn find(name: &str) -> Option<&'static Entity> {
+        if let Some(entity) = ENTITIES.iter().find(|entity| entity.id == name) {
+            return Some(entity);
+        }
+        let folded = fold(name);
+        ENTITIES
+            .iter()
+            .find(|entity| entity.spellings().any(|spelling| fold(spelling) == folded))
+    }
+
+    /// The mentions of this entity in all embedded Writings, in document order.
+    pub fn mentions(&self) -> &'static [Mention] {
+        ENTITIES
+            .iter()
+            .position(|entity| entity.id == self.id)
+            .map_or(&[], |position| &MENTIONS[position])
+    }
+
+    fn spellings(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.variants.iter().map(String::as_str))
+    }
+}
+
+/// A mention of an entity in a text, from [`entity_mentions()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct EntityMatch {
+    /// The `id` of the [`Entity`].
+    pub id: String,
+
+    /// The name of the entity in the gazetteer, e.g. "Mount Carmel".
+    pub name: String,
+
+    pub kind: EntityKind,
+
+    /// The name as written in the text, e.g. "Carmel".
+    pub text: String,
+
+    /// The `char` offset of the name in the text.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the name.
+    pub end: usize,
+}
+
+impl EntityMatch {
+    fn new(text: &str, range: std::ops::Range<usize>, entity: &Entity) -> Self {
+        Self {
+            id: entity.id.clone(),
+            name: entity.name.clone(),
+            kind: entity.kind,
+            text: text[range.clone()].to_string(),
+            start: text[..range.start].chars().count(),
+            end: text[..range.end].chars().count(),
+        }
+    }
+}
+
+/// A mention of an entity in the embedded Writings, from [`mentions()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Mention {
+    /// The `id` of the [`Entity`].
+    pub id: String,
+
+    /// The reference ID of the paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    pub work: Work,
+
+    /// The name as written in the paragraph, e.g. "Carmel".
+    pub text: String,
+
+    /// The `char` offset of the name in the `text` of the paragraph.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the name.
+    pub end: usize,
+}
+
+/// Find the persons and places of the gazetteer mentioned in the text, in order, ignoring
+/// typography and diacritics, and without a possessive "’s". Names must be capitalized, so
+/// that "Lot" is not "lot". The longest name at each word is matched.
+///
+/// ```
+/// use writings::entity_mentions;
+///
+/// let found = entity_mentions("From Baghdad to Acre and Mount Carmel, an acre of land.");
+/// let found = found.iter().map(|m| (m.id.as_str(), m.text.as_str())).collect::<Vec<_>>();
+///
+/// assert_eq!(found, [("baghdad", "Baghdad"), ("akka", "Acre"), ("carmel", "Mount Carmel")]);
+/// ```
+pub fn entity_mentions(text: &str) -> Vec<EntityMatch> {
+    SPELLINGS
+        .find_in(text, true)
+        .into_iter()
+        .map(|(range, position)| EntityMatch::new(text, range, &ENTITIES[position]))
+        .collect()
+}
+
+/// The mentions in all embedded Writings of the person or place with the name (see
+/// [`Entity::find()`]), in document order; empty if there is none in the gazetteer.
+///
+/// ```
+/// use writings::{Work, mentions};
+///
+/// let carmel = mentions("Carmel");
+/// assert!(carmel.iter().any(|m| m.work == Work::Gleanings));
+/// assert!(mentions("Atlantis").is_empty());
+/// ```
+pub fn mentions(name: &str) -> &'static [Mention] {
+    Entity::find(name).map_or(&[], Entity::mentions)
+}
+
+impl Writings {
+    /// The persons and places mentioned in the text of this paragraph,
+    /// see [`entity_mentions()`].
+    pub fn entity_mentions(&self) -> Vec<EntityMatch> {
+        entity_mentions(&self.text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::tests::assert_spellings;
+
+    #[test]
+    fn test_entities() {
+        let items = Entity::all()
+            .iter()
+            .map(|entity| (entity.id.as_str(), entity.spellings().collect()))
+            .collect::<Vec<_>>();
+        assert_spellings(&items, |name| {
+            Entity::find(name).map(|entity| entity.id.as_str())
+        });
+
+        let all_map = Writings::all_map();
+        for id in ["moses", "carmel", "baghdad", "akka", "muhammad"] {
+            let mentions = Entity::find(id).unwrap().mentions();
+            assert!(!mentions.is_empty(), "{id}");
+            for mention in mentions {
+                let text = all_map[&mention.ref_id]
+                    .text()
+                    .chars()
+                    .skip(mention.start)
+                    .take(mention.end - mention.start)
+                    .collect::<String>();
+                assert_eq!(text, mention.text);
+            }
+        }
+        // Lowercase words are not names.
+        assert!(entity_mentions("the lot of the jordan").is_empty());
+        assert_eq!(entity_mentions("the Báb’s Shrine")[0].text, "Báb");
+    }
+}
@@ -8,4 +8,20 @@ pub type WritingsResult<T> = Result<T, WritingsError>;
 pub enum WritingsError {
     #[error("serde deserialize {0}")]
     SerdeValue(#[from] serde::de::value::Error),
+    #[error("io {0}")]
+    Io(#[from] std::io::Error),
+    #[error("json {0}")]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "search")]
+    #[error("invalid search query at {position}: {message}")]
+    SearchQuery { position: usize, message: String },
+    #[cfg(feature = "search")]
+    #[error("search index file is out of date: {0}")]
+    SearchIndexOutdated(String),
+    #[cfg(feature = "tantivy")]
+    #[error("search index {0}")]
+    Tantivy(#[from] tantivy::TantivyError),
+    #[cfg(feature = "tantivy")]
+    #[error("search index directory {0}")]
+    TantivyDirectory(#[from] tantivy::directory::error::OpenDirectoryError),
 }
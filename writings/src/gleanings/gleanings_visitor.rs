@@ -3,6 +3,7 @@
 use std::sync::LazyLock;
 
 use crate::{
+    Toc, Work,
     scraper_ext::{ClassList, ElementExt},
     writings_visitor::{VisitorAction, WritingsVisitor},
 };
@@ -30,6 +31,17 @@ impl WritingsVisitor for GleaningsVisitor {
         &self.gleanings
     }
 
+    fn get_toc(&self) -> Toc {
+        let mut toc = Toc::new(Work::Gleanings.title());
+        for paragraph in &self.gleanings {
+            toc.insert(
+                &[(paragraph.roman.clone(), Some(paragraph.number))],
+                &paragraph.ref_id,
+            );
+        }
+        toc
+    }
+
     fn visit(&mut self, element: &scraper::ElementRef, _level: usize) -> VisitorAction {
         if element.class_list() == *ROMAN_NUMBER_CLASS {
             self.seen_first = true;
@@ -0,0 +1,178 @@
+#![cfg(feature = "embed-all")]
+//! A glossary of the transliterated Persian and Arabic terms of the Writings, e.g. Riḍván,
+//! Mashriqu’l-Adhkár and Naw-Rúz, with their meanings and pronunciations, and detection of
+//! the terms in any text.
+
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::text::{Phrases, fold};
+use crate::{Writings, WritingsTrait as _};
+
+/// The glossary, in alphabetical order of `id`.
+static GLOSSARY: LazyLock<Vec<GlossaryTerm>> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("glossary.json"))
+        .unwrap_or_else(|err| panic!("Invalid glossary.json: {err}"))
+});
+
+/// The spellings of all terms, with the position of the term in the glossary.
+static SPELLINGS: LazyLock<Phrases> = LazyLock::new(|| {
+    Phrases::new(
+        GLOSSARY
+            .iter()
+            .enumerate()
+            .flat_map(|(position, term)| term.spellings().map(move |s| (s, position))),
+    )
+});
+
+/// A transliterated term of the glossary, see [`GlossaryTerm::all()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GlossaryTerm {
+    /// The term in lowercase ASCII, e.g. "naw-ruz".
+    pub id: String,
+
+    /// The term as transliterated in the Writings, e.g. "Naw-Rúz".
+    pub term: String,
+
+    /// Other spellings and forms of the term, e.g. "Nowruz". Spellings that differ only in
+    /// case, typography or diacritics (see [`crate::normalize::fold()`]) match anyway.
+    pub variants: Vec<String>,
+
+    /// The meaning of the term, e.g. "New Day; the Bahá’í and Persian New Year, …".
+    pub meaning: String,
+
+    /// The pronunciation in the International Phonetic Alphabet, as in Persian, e.g. "nowˈruːz".
+    pub ipa: String,
+
+    /// The pronunciation respelled in English, with the stressed syllable in capitals,
+    /// e.g. "now-ROOZ".
+    pub pronunciation: String,
+}
+
+impl GlossaryTerm {
+    /// All terms of the glossary, in alphabetical order of `id`.
+    pub fn all() -> &'static [GlossaryTerm] {
+        &GLOSSARY
+    }
+
+    /// Find a term by its `id`, or by its `term` or one of its `variants`, ignoring case,
+    /// typography and diacritics.
+    ///
+    /// ```
+    /// use writings::GlossaryTerm;
+    ///
+    /// let term = GlossaryTerm::find("Rizwan").unwrap();
+    /// assert_eq!(term.term, "Riḍván");
+    /// assert_eq!(GlossaryTerm::find("mashriqu'l-adhkar").unwrap().id, "mashriqul-adhkar");
+    /// ```
+    pub fn find(name: &str) -> Option<&'static GlossaryTerm> {
+        if let Some(term) = GLOSSARY.iter().find(|term| term.id == name) {
+            return Some(term);
+        }
+        let folded = fold(name);
+        GLOSSARY
+            .iter()
+            .find(|term| term.spellings().any(|spelling| fold(spelling) == folded))
+    }
+
+    fn spellings(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.term.as_str()).chain(self.variants.iter().map(String::as_str))
+    }
+}
+
+/// A glossary term in a text, from [`glossary_terms()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GlossaryMatch {
+    /// The `id` of the [`GlossaryTerm`].
+    pub id: String,
+
+    /// The term as transliterated in the glossary, e.g. "Riḍván".
+    pub term: String,
+
+    /// The term as written in the text, e.g. "Ridvan".
+    pub text: String,
+
+    /// The `char` offset of the term in the text.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the term.
+    pub end: usize,
+}
+
+/// Find the glossary terms in the text, in order, ignoring case, typography and diacritics,
+/// and without a possessive "’s". The longest spelling of a term at each word is matched.
+///
+/// ```
+/// use writings::glossary_terms;
+///
+/// let terms = glossary_terms("At Naw-Ruz and in the Riḍván’s garden, Yá Bahá’u’l-Abhá!");
+/// let found = terms.iter().map(|m| (m.id.as_str(), m.text.as_str())).collect::<Vec<_>>();
+///
+/// assert_eq!(found, [("naw-ruz", "Naw-Ruz"), ("ridvan", "Riḍván"), ("ya", "Yá")]);
+/// assert_eq!((terms[1].start, terms[1].end), (22, 28));
+/// ```
+pub fn glossary_terms(text: &str) -> Vec<GlossaryMatch> {
+    SPELLINGS
+        .find_in(text, false)
+        .into_iter()
+        .map(|(range, position)| {
+            let term = &GLOSSARY[position];
+            GlossaryMatch {
+                id: term.id.clone(),
+                term: term.term.clone(),
+                text: text[range.clone()].to_string(),
+                start: text[..range.start].chars().count(),
+                end: text[..range.end].chars().count(),
+            }
+        })
+        .collect()
+}
+
+impl Writings {
+    /// The glossary terms in the text of this paragraph, see [`glossary_terms()`].
+    pub fn glossary_terms(&self) -> Vec<GlossaryMatch> {
+        glossary_terms(&self.text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EmbedAllTrait as _;
+    use crate::text::tests::assert_spellings;
+
+    #[test]
+    fn test_glossary() {
+        let all = GlossaryTerm::all();
+        let items = all
+            .iter()
+            .map(|term| (term.id.as_str(), term.spellings().collect()))
+            .collect::<Vec<_>>();
+        assert_spellings(&items, |name| {
+            GlossaryTerm::find(name).map(|term| term.id.as_str())
+        });
+        assert!(
+            all.iter()
+                .all(|term| !term.meaning.is_empty() && !term.ipa.is_empty())
+        );
+
+        let writings = Writings::all();
+        let found = writings
+            .iter()
+            .flat_map(|w| w.glossary_terms())
+            .collect::<Vec<_>>();
+        for id in ["bayan", "quran", "naw-ruz", "ridvan", "kitab-i-aqdas"] {
+            assert!(found.iter().any(|m| m.id == id), "{id}");
+        }
+        // “‘Abdu’l-Bahá” and “Bahá’u’lláh” are not the term “Bahá”.
+        let text = "‘Abdu’l-Bahá and Bahá’u’lláh";
+        assert!(glossary_terms(text).is_empty());
+    }
+}
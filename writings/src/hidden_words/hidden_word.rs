@@ -2,7 +2,7 @@ use enumscribe::{EnumDeserialize, EnumSerialize, ScribeStaticStr};
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
-use crate::{WritingsTrait, WritingsType, author::Author};
+use crate::{Work, WritingsTrait, WritingsType, author::Author};
 
 /// A single Hidden Word, or the "Prologue" or "Epilogue", from <a href="https://www.bahai.org/library/authoritative-texts/bahaullah/hidden-words/" target="_blank">_The Hidden Words_ of Bahá’u’lláh</a>
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -53,8 +53,12 @@ impl WritingsTrait<HiddenWord> for HiddenWord {
         self.ref_id.clone()
     }
 
+    fn work(&self) -> Work {
+        Work::HiddenWords
+    }
+
     fn title(&self) -> String {
-        "The Hidden Words".to_string()
+        Work::HiddenWords.title()
     }
 
     fn subtitle(&self) -> Option<String> {
@@ -90,6 +94,7 @@ impl WritingsTrait<HiddenWord> for HiddenWord {
     Copy,
     PartialEq,
     Eq,
+    Hash,
 )]
 #[enumscribe(case_insensitive)]
 #[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
@@ -125,7 +130,7 @@ impl indicium::simple::Indexable for HiddenWord {
             &self.kind.to_string(),
             self.prelude.as_deref().unwrap_or_default(),
             self.invocation.as_deref().unwrap_or_default(),
-            &diacritics::remove_diacritics(&self.text),
+            &crate::normalize::fold_diacritics(&self.text),
         ]
         .iter()
         .filter_map(|s| {
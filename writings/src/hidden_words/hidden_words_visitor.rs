@@ -5,6 +5,7 @@ use std::sync::LazyLock;
 use scraper::Selector;
 
 use crate::{
+    Toc, Work,
     scraper_ext::{ClassList, ElementExt},
     writings_visitor::{VisitorAction, WritingsVisitor},
 };
@@ -36,6 +37,26 @@ impl WritingsVisitor for HiddenWordsVisitor {
         &self.hidden_words
     }
 
+    fn get_toc(&self) -> Toc {
+        let mut toc = Toc::new(Work::HiddenWords.title());
+        for hidden_word in &self.hidden_words {
+            let title = match (hidden_word.number, &hidden_word.invocation) {
+                (Some(_), Some(invocation)) => invocation.clone(),
+                (Some(number), None) => number.to_string(),
+                (None, _) if hidden_word.kind == HiddenWordKind::Arabic => "Prologue".to_string(),
+                (None, _) => "Epilogue".to_string(),
+            };
+            toc.insert(
+                &[
+                    (hidden_word.kind.title().to_string(), None),
+                    (title, hidden_word.number),
+                ],
+                &hidden_word.ref_id,
+            );
+        }
+        toc
+    }
+
     fn visit(&mut self, element: &scraper::ElementRef, _level: usize) -> VisitorAction {
         // Preludes
         if self.current_kind == HiddenWordKind::Persian
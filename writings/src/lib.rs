@@ -133,24 +133,48 @@ mod cdb;
 pub use cdb::CDBParagraph;
 mod citation;
 pub use citation::Citation;
+mod chunk;
+pub use chunk::{CHARS_PER_TOKEN, Chunk, ChunkOptions, ChunkRange, ChunkSize, chunk, write_jsonl};
 mod embed_all;
 #[cfg(feature = "_embed-any")]
 pub use embed_all::EmbedAllTrait;
+mod entity;
+#[cfg(feature = "embed-all")]
+pub use entity::{Entity, EntityKind, EntityMatch, Mention, entity_mentions, mentions};
 mod error;
 pub use error::{WritingsError, WritingsResult};
 mod meditations;
 pub use meditations::MeditationParagraph;
 mod gleanings;
 pub use gleanings::GleaningsParagraph;
+mod glossary;
+#[cfg(feature = "embed-all")]
+pub use glossary::{GlossaryMatch, GlossaryTerm, glossary_terms};
 mod hidden_words;
 pub use hidden_words::{HiddenWord, HiddenWordKind};
 mod paragraph_style;
 pub use paragraph_style::ParagraphStyle;
+mod passages;
+#[cfg(feature = "embed-all")]
+pub use passages::SharedPassage;
 mod prayers;
 pub use prayers::{PrayerKind, PrayerParagraph, PrayerSource};
+mod query;
+#[cfg(feature = "embed-all")]
+pub use query::Query;
+pub mod normalize;
 pub mod roman;
 mod scraper_ext;
+pub mod search;
+mod sentence;
+pub use sentence::{Sentence, sentence_ranges};
+pub mod stats;
+mod text;
+mod toc;
+pub use toc::Toc;
 use writings_macros::WritingsTrait;
+mod work;
+pub use work::{Work, WorkInfo};
 mod writings_trait;
 pub use writings_trait::WritingsTrait;
 mod writings;
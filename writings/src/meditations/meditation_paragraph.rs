@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{WritingsTrait, WritingsType, author::Author};
+use crate::{Work, WritingsTrait, WritingsType, author::Author};
 
 /// A single paragraph from <a href="https://www.bahai.org/library/authoritative-texts/bahaullah/prayers-meditations/" target="_blank">_Prayers and Meditations by Bahá’u’lláh_</a>
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -46,8 +46,12 @@ impl WritingsTrait<MeditationParagraph> for MeditationParagraph {
         self.ref_id.clone()
     }
 
+    fn work(&self) -> Work {
+        Work::PrayersAndMeditations
+    }
+
     fn title(&self) -> String {
-        "Prayers and Meditations".to_string()
+        Work::PrayersAndMeditations.title()
     }
 
     fn subtitle(&self) -> Option<String> {
@@ -85,7 +89,7 @@ impl indicium::simple::Indexable for MeditationParagraph {
         [
             self.ref_id.as_str(),
             self.roman.as_str(),
-            &diacritics::remove_diacritics(&self.text),
+            &crate::normalize::fold_diacritics(&self.text),
         ]
         .iter()
         .filter_map(|s| {
@@ -3,6 +3,7 @@
 use std::sync::LazyLock;
 
 use crate::{
+    Toc, Work,
     scraper_ext::{ClassList, ElementExt},
     writings_visitor::{VisitorAction, WritingsVisitor},
 };
@@ -30,6 +31,17 @@ impl WritingsVisitor for MeditationsVisitor {
         &self.meditation_text
     }
 
+    fn get_toc(&self) -> Toc {
+        let mut toc = Toc::new(Work::PrayersAndMeditations.title());
+        for paragraph in &self.meditation_text {
+            toc.insert(
+                &[(paragraph.roman.clone(), Some(paragraph.number))],
+                &paragraph.ref_id,
+            );
+        }
+        toc
+    }
+
     fn visit(&mut self, element: &scraper::ElementRef, _level: usize) -> VisitorAction {
         if element.class_list() == *ROMAN_NUMBER_CLASS {
             self.seen_first = true;
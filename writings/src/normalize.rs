@@ -0,0 +1,303 @@
+//! Unicode normalization, and folding of typography (curly vs. straight apostrophes and
+//! quotation marks, non-breaking vs. ASCII hyphens) and of the diacritics of transliterated
+//! names, so that e.g. "Bahá’u’lláh", "Bahá'u'lláh" and "BAHAULLAH" can be compared.
+//!
+//! The embedded Writings are in NFC with typographic punctuation, as in the Bahá’í Reference
+//! Library; see [`lint()`] for inconsistencies.
+
+use std::{borrow::Cow, fmt};
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{UnicodeNormalization as _, char::is_combining_mark};
+
+/// The text in Unicode Normalization Form C, e.g. "á" as one `char` rather than "a" and a
+/// combining acute accent.
+///
+/// ```
+/// use writings::normalize::nfc;
+///
+/// assert_eq!(nfc("Baha\u{301}’u’lla\u{301}h"), "Bahá’u’lláh");
+/// ```
+pub fn nfc(text: &str) -> Cow<'_, str> {
+    match unicode_normalization::is_nfc(text) {
+        true => Cow::Borrowed(text),
+        false => Cow::Owned(text.nfc().collect()),
+    }
+}
+
+/// The ASCII equivalent of a typographic character: apostrophes, ‘ayn and single quotation
+/// marks, double quotation marks, hyphens and dashes, spaces and the ellipsis.
+pub fn ascii_typography(c: char) -> Option<&'static str> {
+    Some(match c {
+        '‘' | '’' | '‚' | '‛' | 'ʻ' | 'ʼ' | 'ʽ' | '′' => "'",
+        '“' | '”' | '„' | '‟' | '″' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => "-",
+        '\u{a0}' | '\u{2002}'..='\u{200a}' | '\u{202f}' => " ",
+        '…' => "...",
+        _ => return None,
+    })
+}
+
+/// The text with typographic characters replaced by their ASCII equivalents
+/// (see [`ascii_typography()`]).
+///
+/// ```
+/// use writings::normalize::fold_typography;
+///
+/// assert_eq!(fold_typography("‘Abdu’l‑Bahá said, “Yes…”"), "'Abdu'l-Bahá said, \"Yes...\"");
+/// ```
+pub fn fold_typography(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|c| ascii_typography(c).is_some()) {
+        return Cow::Borrowed(text);
+    }
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        match ascii_typography(c) {
+            Some(ascii) => folded.push_str(ascii),
+            None => folded.push(c),
+        }
+    }
+    Cow::Owned(folded)
+}
+
+/// The text without diacritics (combining marks, after decomposition), e.g. the accents and
+/// dots of transliterated names: "Ṭihrán" becomes "Tihran".
+///
+/// ```
+/// use writings::normalize::fold_diacritics;
+///
+/// assert_eq!(fold_diacritics("Ṭihrán and Kitáb-i-Íqán"), "Tihran and Kitab-i-Iqan");
+/// ```
+pub fn fold_diacritics(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.nfd()
+            .filter(|&c| !is_combining_mark(c))
+            .nfc()
+            .collect(),
+    )
+}
+
+/// The text in NFC, without typography or diacritics, in lowercase: the form in which the
+/// Writings are looked up and searched.
+///
+/// ```
+/// use writings::normalize::fold;
+///
+/// assert_eq!(fold("‘Abdu’l‑Bahá"), "'abdu'l-baha");
+/// assert_eq!(fold("Bahá’u’lláh"), fold("BAHA'U'LLAH"));
+/// ```
+pub fn fold(text: &str) -> String {
+    fold_diacritics(&fold_typography(&nfc(text))).to_lowercase()
+}
+
+/// The kind of a [`LintIssue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum LintKind {
+    /// Text that is not in Unicode Normalization Form C (see [`nfc()`]).
+    NotNfc,
+    /// A word spelled with other apostrophes or hyphens than elsewhere, e.g. "Bahá'u'lláh"
+    /// for "Bahá’u’lláh", or "‘Abdu’l-Bahá" (ASCII hyphen) for "‘Abdu’l‑Bahá"
+    /// (non-breaking hyphen).
+    Typography,
+}
+
+/// An inconsistency in the text or titles of the embedded Writings, from [`lint()`].
+///
+/// Displays as e.g. "TabletSource::AdditionalTabletsAndExtractsBahaullah: “Bahá'u'lláh”
+/// should be “Bahá’u’lláh”".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct LintIssue {
+    pub kind: LintKind,
+
+    /// Where the text is: the `ref_id` of a paragraph, or the type and variant of a title,
+    /// e.g. "Work::GleaningsFromTheWritingsOfBahaullah".
+    pub location: String,
+
+    /// The text as written: a word, or the whole text if not in NFC.
+    pub text: String,
+
+    /// The text as it should be written: in NFC, or with the most common spelling.
+    pub expected: String,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: “{}” should be “{}”",
+            self.location, self.text, self.expected
+        )
+    }
+}
+
+/// Check the text, subtitles and titles of all embedded Writings, and the names of the
+/// Works and authors, for text not in NFC and words spelled with different apostrophes or
+/// hyphens in different places (ignoring case). The most common spelling of a word is
+/// expected everywhere.
+///
+/// ```
+/// use writings::normalize::lint;
+///
+/// let issues = lint();
+/// assert!(issues.is_empty(), "{}", issues.iter().map(|i| i.to_string())
+///     .collect::<Vec<_>>().join("\n"));
+/// ```
+#[cfg(feature = "embed-all")]
+pub fn lint() -> Vec<LintIssue> {
+    use std::collections::{HashMap, HashSet};
+
+    use strum::IntoEnumIterator as _;
+
+    use crate::{
+        Author, BookTitle, EmbedAllTrait as _, PrayerSource, TabletSource, Work, Writings,
+        WritingsTrait as _,
+    };
+
+    let mut sources: Vec<(String, String)> = vec![];
+    let mut subtitles = HashSet::new();
+    for w in Writings::all().iter() {
+        sources.push((w.ref_id(), w.text()));
+        if let Some(subtitle) = w.subtitle()
+            && subtitles.insert(subtitle.clone())
+        {
+            sources.push((w.ref_id(), subtitle));
+        }
+    }
+    let titles = |name: &str, titles: Vec<(String, String)>| {
+        titles
+            .into_iter()
+            .map(move |(variant, title)| (format!("{name}::{variant}"), title))
+            .collect::<Vec<_>>()
+    };
+    sources.extend(titles(
+        "Work",
+        Work::iter()
+            .map(|t| (format!("{t:?}"), t.to_string()))
+            .collect(),
+    ));
+    sources.extend(titles(
+        "BookTitle",
+        BookTitle::iter()
+            .map(|t| (format!("{t:?}"), t.to_string()))
+            .collect(),
+    ));
+    sources.extend(titles(
+        "TabletSource",
+        TabletSource::iter()
+            .map(|t| (format!("{t:?}"), t.to_string()))
+            .collect(),
+    ));
+    sources.extend(titles(
+        "PrayerSource",
+        PrayerSource::iter()
+            .map(|t| (format!("{t:?}"), t.to_string()))
+            .collect(),
+    ));
+    sources.extend(titles(
+        "Author",
+        Author::iter()
+            .map(|t| (format!("{t:?}"), t.to_string()))
+            .collect(),
+    ));
+
+    let mut issues = vec![];
+    // The number of times each spelling of each word is used, by its folded typography.
+    let mut spellings: HashMap<String, HashMap<String, usize>> = HashMap::new();
+    let mut words = vec![];
+    for (location, text) in &sources {
+        if let Cow::Owned(expected) = nfc(text) {
+            issues.push(LintIssue {
+                kind: LintKind::NotNfc,
+                location: location.clone(),
+                text: text.clone(),
+                expected,
+            });
+        }
+        for range in crate::text::word_ranges(text) {
+            let word = &text[range];
+            if word.chars().all(char::is_alphanumeric) {
+                continue;
+            }
+            let spelling = word.to_lowercase();
+            *spellings
+                .entry(fold_typography(&spelling).into_owned())
+                .or_default()
+                .entry(spelling)
+                .or_default() += 1;
+            words.push((location, word));
+        }
+    }
+
+    let mut seen = HashSet::new();
+    for (location, word) in words {
+        let spelling = word.to_lowercase();
+        let counts = &spellings[fold_typography(&spelling).as_ref()];
+        let Some((expected, _)) = counts
+            .iter()
+            .max_by_key(|&(spelling, count)| (count, spelling))
+        else {
+            continue;
+        };
+        if *expected == spelling || !seen.insert((location, word)) {
+            continue;
+        }
+        // The word as written, with the punctuation of the expected spelling.
+        let expected = word
+            .chars()
+            .zip(expected.chars())
+            .map(|(c, e)| if c.is_alphanumeric() { c } else { e })
+            .collect();
+        issues.push(LintIssue {
+            kind: LintKind::Typography,
+            location: location.clone(),
+            text: word.to_string(),
+            expected,
+        });
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(fold_typography("plain text"), Cow::Borrowed("plain text"));
+        assert_eq!(
+            fold_typography("well‑being — ‘words’"),
+            "well-being - 'words'"
+        );
+        assert_eq!(fold_diacritics("Mázindarán"), "Mazindaran");
+        assert_eq!(fold("‘ABDU’L‑BAHÁ"), fold("'Abdu'l-Baha"));
+        assert_eq!(fold("Baha\u{301}’i\u{301}"), "baha'i");
+    }
+
+    #[cfg(feature = "embed-all")]
+    #[test]
+    fn test_lint() {
+        use crate::{Query, TabletSource};
+
+        assert_eq!(lint(), vec![]);
+        assert!(
+            TabletSource::AdditionalTabletsAndExtractsBahaullah
+                .to_string()
+                .ends_with("Bahá’u’lláh")
+        );
+        // Lookups fold typography as well as diacritics.
+        assert_eq!(
+            Query::new().text_contains("Baha'u'llah").execute().len(),
+            Query::new().text_contains("Bahá’u’lláh").execute().len()
+        );
+    }
+}
@@ -0,0 +1,267 @@
+#![cfg(feature = "embed-all")]
+
+use std::{
+    collections::{HashMap, hash_map::DefaultHasher},
+    fmt,
+    hash::{Hash, Hasher},
+    sync::LazyLock,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::text::{fold, word_ranges};
+use crate::{EmbedAllTrait as _, Work, Writings, WritingsTrait as _};
+
+/// The number of consecutive words (n-grams) compared between paragraphs.
+const SHINGLE_WORDS: usize = 8;
+
+/// N-grams in more paragraphs than this are formulae (e.g. "Thou art, verily, the Mighty,
+/// the Gracious") rather than shared passages, and are ignored.
+const MAX_SHINGLE_PARAGRAPHS: usize = 6;
+
+/// The minimum number of words of a shared passage.
+const MIN_PASSAGE_WORDS: usize = 12;
+
+/// The shared passages of each paragraph, by position in `Writings::all()`.
+static SHARED_PASSAGES: LazyLock<HashMap<usize, Vec<SharedPassage>>> =
+    LazyLock::new(find_shared_passages);
+
+/// A passage of a paragraph that also appears in a paragraph of another Work, e.g. a prayer
+/// in both Bahá’í Prayers and Prayers and Meditations, or a Gleaning drawn from the
+/// Kitáb-i-Íqán. See [`Writings::appears_in()`] and [`Writings::sources()`].
+///
+/// Displays as the other paragraph, e.g. "Prayers and Meditations, 176 ¶2".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct SharedPassage {
+    /// The reference ID of the other paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    /// The Work of the other paragraph.
+    pub work: Work,
+
+    /// The `char` offset of the passage in the `text` of this paragraph.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the passage in this paragraph.
+    pub end: usize,
+
+    /// The `char` offset of the passage in the `text` of the other paragraph.
+    pub other_start: usize,
+
+    /// The `char` offset just past the end of the passage in the other paragraph.
+    pub other_end: usize,
+
+    /// The number of words of the passage (in this paragraph).
+    pub words: usize,
+
+    /// The other paragraph.
+    pub item: Writings,
+}
+
+impl fmt::Display for SharedPassage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.work)?;
+        if let Some(number) = self.item.number() {
+            write!(f, ", {number}")?;
+        }
+        match self.item.paragraph() {
+            0 => Ok(()),
+            paragraph => write!(f, " ¶{paragraph}"),
+        }
+    }
+}
+
+impl Writings {
+    /// The passages of this paragraph that also appear in other Works, in order of their
+    /// position in this paragraph, e.g. the same prayer in another compilation.
+    ///
+    /// ```
+    /// use writings::{EmbedAllTrait as _, Work, Writings, WritingsTrait as _};
+    ///
+    /// let prayer = Writings::all()
+    ///     .iter()
+    ///     .find(|w| w.work() == Work::BahaiPrayers
+    ///         && w.appears_in().iter().any(|p| p.work == Work::PrayersAndMeditations))
+    ///     .unwrap()
+    ///     .clone();
+    /// let passage = &prayer.appears_in()[0];
+    /// let text = prayer.text().chars().skip(passage.start).take(passage.end - passage.start)
+    ///     .collect::<String>();
+    /// let other = passage.item.text().chars().skip(passage.other_start)
+    ///     .take(passage.other_end - passage.other_start).collect::<String>();
+    ///
+    /// assert!(passage.words >= 12);
+    /// assert_eq!(text.to_lowercase(), other.to_lowercase());
+    /// ```
+    pub fn appears_in(&self) -> Vec<SharedPassage> {
+        self.position()
+            .and_then(|position| SHARED_PASSAGES.get(&position))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The passages of this paragraph drawn from Works that are not compilations
+    /// (see [`Work::is_compilation()`]), e.g. the Kitáb-i-Íqán for a Gleaning.
+    /// Empty for a paragraph of a Work that is not a compilation itself.
+    pub fn sources(&self) -> Vec<SharedPassage> {
+        if !self.work().is_compilation() {
+            return vec![];
+        }
+        self.appears_in()
+            .into_iter()
+            .filter(|passage| !passage.work.is_compilation())
+            .collect()
+    }
+}
+
+/// A paragraph's words: their byte ranges in the text, and their folded hashes.
+struct Words {
+    ranges: Vec<std::ops::Range<usize>>,
+    hashes: Vec<u64>,
+}
+
+/// Find the runs of n-grams that paragraphs of different Works have in common, allowing
+/// for a changed word (a gap of up to `SHINGLE_WORDS` n-grams) within a run.
+fn find_shared_passages() -> HashMap<usize, Vec<SharedPassage>> {
+    let all = Writings::all();
+    let texts = all.iter().map(|w| w.text()).collect::<Vec<_>>();
+    let words = texts
+        .iter()
+        .map(|text| {
+            let (ranges, hashes) = word_ranges(text)
+                .filter_map(|range| {
+                    let folded = fold(&text[range.clone()]);
+                    (!folded.is_empty()).then(|| (range, hash(&folded)))
+                })
+                .unzip();
+            Words { ranges, hashes }
+        })
+        .collect::<Vec<_>>();
+
+    let mut shingles: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+    for (position, words) in words.iter().enumerate() {
+        for (i, window) in words.hashes.windows(SHINGLE_WORDS).enumerate() {
+            shingles
+                .entry(hash(window))
+                .or_default()
+                .push((position, i));
+        }
+    }
+
+    // The n-grams in common by pair of paragraphs and diagonal (offset between them).
+    let mut hits: HashMap<(usize, usize, isize), Vec<usize>> = HashMap::new();
+    for occurrences in shingles.values() {
+        if occurrences.len() > MAX_SHINGLE_PARAGRAPHS {
+            continue;
+        }
+        for (k, &(a, i)) in occurrences.iter().enumerate() {
+            for &(b, j) in &occurrences[k + 1..] {
+                if all[a].work() != all[b].work() {
+                    hits.entry((a, b, i as isize - j as isize))
+                        .or_default()
+                        .push(i);
+                }
+            }
+        }
+    }
+
+    let chars = |position: usize, byte: usize| texts[position][..byte].chars().count();
+    let mut shared: HashMap<usize, Vec<SharedPassage>> = HashMap::new();
+    for ((a, b, diagonal), mut starts) in hits {
+        starts.sort_unstable();
+        let mut runs: Vec<(usize, usize)> = vec![];
+        for i in starts {
+            match runs.last_mut() {
+                Some((_, end)) if i <= *end + SHINGLE_WORDS => *end = i,
+                _ => runs.push((i, i)),
+            }
+        }
+        for (first, last) in runs {
+            let (start, end) = (first, last + SHINGLE_WORDS);
+            if end - start < MIN_PASSAGE_WORDS {
+                continue;
+            }
+            let (other_start, other_end) = (
+                (start as isize - diagonal) as usize,
+                (end as isize - diagonal) as usize,
+            );
+            let a_bytes = words[a].ranges[start].start..words[a].ranges[end - 1].end;
+            let b_bytes = words[b].ranges[other_start].start..words[b].ranges[other_end - 1].end;
+            let (a_chars, b_chars) = (
+                chars(a, a_bytes.start)..chars(a, a_bytes.end),
+                chars(b, b_bytes.start)..chars(b, b_bytes.end),
+            );
+            let words = end - start;
+            let passage =
+                |other: usize, this: &std::ops::Range<usize>, that: &std::ops::Range<usize>| {
+                    SharedPassage {
+                        ref_id: all[other].ref_id(),
+                        work: all[other].work(),
+                        start: this.start,
+                        end: this.end,
+                        other_start: that.start,
+                        other_end: that.end,
+                        words,
+                        item: all[other].clone(),
+                    }
+                };
+            shared
+                .entry(a)
+                .or_default()
+                .push(passage(b, &a_chars, &b_chars));
+            shared
+                .entry(b)
+                .or_default()
+                .push(passage(a, &b_chars, &a_chars));
+        }
+    }
+    for passages in shared.values_mut() {
+        passages.sort_by(|x, y| (x.start, &x.ref_id).cmp(&(y.start, &y.ref_id)));
+    }
+    shared
+}
+
+fn hash(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_passages() {
+        let all = Writings::all();
+        let shared = all
+            .iter()
+            .filter(|w| !w.appears_in().is_empty())
+            .collect::<Vec<_>>();
+        assert!(!shared.is_empty());
+
+        for w in shared {
+            for passage in w.appears_in() {
+                assert_ne!(passage.work, w.work());
+                // Every link is recorded in both directions.
+                assert!(
+                    passage
+                        .item
+                        .appears_in()
+                        .iter()
+                        .any(|p| p.ref_id == w.ref_id() && p.start == passage.other_start),
+                    "{passage:?}"
+                );
+            }
+            assert!(
+                w.sources()
+                    .iter()
+                    .all(|p| w.work().is_compilation() && !p.work.is_compilation())
+            );
+        }
+    }
+}
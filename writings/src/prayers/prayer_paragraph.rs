@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Citation, ParagraphStyle, WritingsTrait, WritingsType, author::Author};
+use crate::{Citation, ParagraphStyle, Work, WritingsTrait, WritingsType, author::Author};
 
 use super::{PrayerKind, prayer_source::PrayerSource};
 
@@ -91,6 +91,10 @@ impl WritingsTrait<PrayerParagraph> for PrayerParagraph {
         self.ref_id.to_string()
     }
 
+    fn work(&self) -> Work {
+        Work::from(self.source)
+    }
+
     fn title(&self) -> String {
         self.source.title()
     }
@@ -129,6 +133,10 @@ impl WritingsTrait<PrayerParagraph> for PrayerParagraph {
     fn text(&self) -> String {
         self.text.clone()
     }
+
+    fn style(&self) -> ParagraphStyle {
+        self.style
+    }
 }
 
 #[cfg(feature = "indicium")]
@@ -138,7 +146,7 @@ impl indicium::simple::Indexable for PrayerParagraph {
             self.ref_id.as_str(),
             &self.kind.to_string(),
             &self.section.join(" "),
-            &diacritics::remove_diacritics(&self.text),
+            &crate::normalize::fold_diacritics(&self.text),
         ]
         .iter()
         .filter_map(|s| {
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::Work;
+
 /// A compilation of Bahá’í Prayers, the most well-known perhaps being [`PrayerSource::BahaiPrayers`]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
 #[cfg_attr(
@@ -42,6 +46,6 @@ pub enum PrayerSource {
 
 impl PrayerSource {
     pub fn title(&self) -> String {
-        self.to_string()
+        Work::from(*self).title()
     }
 }
@@ -6,9 +6,10 @@ use scraper::{ElementRef, Selector};
 use strum::IntoEnumIterator;
 
 use crate::{
-    ParagraphStyle,
+    ParagraphStyle, Toc,
     author::Author,
     scraper_ext::{ClassList, ElementExt as _},
+    toc::opening_words,
     writings_visitor::{CitationText, VisitorAction, WritingsVisitor, resolve_citations},
 };
 
@@ -47,6 +48,27 @@ impl WritingsVisitor for PrayersVisitor {
         &self.prayers
     }
 
+    fn get_toc(&self) -> Toc {
+        let mut toc = Toc::new(PrayerSource::BahaiPrayers.title());
+        for prayer in self.prayers.chunk_by(|a, b| a.number == b.number) {
+            // Title each prayer by the opening words of its Text, skipping any instructions.
+            let opening = prayer
+                .iter()
+                .find(|p| p.style != ParagraphStyle::Instruction)
+                .unwrap_or(&prayer[0]);
+            // Instructions may precede the section heading, so file the whole prayer
+            // under the section of its opening paragraph.
+            let mut path = vec![(opening.kind.title(), None)];
+            path.extend(opening.section.iter().map(|s| (s.clone(), None)));
+            path.push((opening_words(&opening.text, 8), Some(opening.number)));
+
+            for paragraph in prayer {
+                toc.insert(&path, &paragraph.ref_id);
+            }
+        }
+        toc
+    }
+
     fn visit(&mut self, element: &ElementRef, html_level: usize) -> VisitorAction {
         let name = element.value().name();
 
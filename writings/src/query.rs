@@ -0,0 +1,350 @@
+#![cfg(feature = "embed-all")]
+
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+    ops::{Bound, RangeBounds},
+    sync::{Arc, OnceLock},
+};
+
+use crate::normalize::fold;
+use crate::{
+    Author, EmbedAllTrait as _, HiddenWordKind, ParagraphStyle, PrayerKind, Work, Writings,
+    WritingsTrait as _, WritingsType,
+};
+
+/// A typed query over all embedded [`Writings`].
+///
+/// Filters on different fields must all match; calling the same filter more than once
+/// matches any of the given values (except [`Query::section`] and the text filters, which must all match).
+/// Results are returned in document order.
+///
+/// ```
+/// use writings::{Author, PrayerKind, Query, WritingsType};
+///
+/// let prayers = Query::new()
+///     .ty(WritingsType::Prayer)
+///     .author(Author::AbdulBaha)
+///     .prayer_kind(PrayerKind::General)
+///     .section("Children")
+///     .text_contains("lovely children")
+///     .execute();
+///
+/// assert_eq!(prayers.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    types: Vec<WritingsType>,
+    authors: Vec<Author>,
+    works: Vec<Work>,
+    prayer_kinds: Vec<PrayerKind>,
+    hidden_word_kinds: Vec<HiddenWordKind>,
+    styles: Vec<ParagraphStyle>,
+    numbers: Option<(Bound<u32>, Bound<u32>)>,
+    paragraphs: Option<(Bound<u32>, Bound<u32>)>,
+    sections: Vec<String>,
+    texts: Vec<TextPredicate>,
+}
+
+/// A predicate on the `text` of [`Writings`].
+#[derive(Clone)]
+struct TextPredicate(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl fmt::Debug for TextPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TextPredicate")
+    }
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match the given type of Writings.
+    pub fn ty(mut self, ty: WritingsType) -> Self {
+        self.types.push(ty);
+        self
+    }
+
+    /// Match Writings by the given Author.
+    pub fn author(mut self, author: Author) -> Self {
+        self.authors.push(author);
+        self
+    }
+
+    /// Match Writings from the given Work.
+    pub fn work(mut self, work: Work) -> Self {
+        self.works.push(work);
+        self
+    }
+
+    /// Match prayers of the given kind.
+    pub fn prayer_kind(mut self, kind: PrayerKind) -> Self {
+        self.prayer_kinds.push(kind);
+        self
+    }
+
+    /// Match Hidden Words of the given kind.
+    pub fn hidden_word_kind(mut self, kind: HiddenWordKind) -> Self {
+        self.hidden_word_kinds.push(kind);
+        self
+    }
+
+    /// Match paragraphs of the given style.
+    pub fn style(mut self, style: ParagraphStyle) -> Self {
+        self.styles.push(style);
+        self
+    }
+
+    /// Match Writings with a `number()` (Gleaning, prayer, Hidden Word, etc.) within the range.
+    pub fn number(mut self, range: impl RangeBounds<u32>) -> Self {
+        self.numbers = Some((range.start_bound().cloned(), range.end_bound().cloned()));
+        self
+    }
+
+    /// Match Writings with a `paragraph()` number within the range.
+    pub fn paragraph(mut self, range: impl RangeBounds<u32>) -> Self {
+        self.paragraphs = Some((range.start_bound().cloned(), range.end_bound().cloned()));
+        self
+    }
+
+    /// Match prayers with a section or subsection containing `section`,
+    /// ignoring case, typography and diacritics (see [`fold()`](crate::normalize::fold)), e.g. "western" matches
+    /// "Revealed to the Bahá’ís of the Western States".
+    pub fn section(mut self, section: &str) -> Self {
+        self.sections.push(fold(section));
+        self
+    }
+
+    /// Match Writings whose text contains `text`, ignoring case, typography and diacritics,
+    /// e.g. "Baha'u'llah" matches "Bahá’u’lláh".
+    pub fn text_contains(self, text: &str) -> Self {
+        let text = fold(text);
+        self.text(move |t| fold(t).contains(&text))
+    }
+
+    /// Match Writings whose text satisfies the predicate.
+    pub fn text(mut self, predicate: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        self.texts.push(TextPredicate(Arc::new(predicate)));
+        self
+    }
+
+    /// Whether the given Writings matches all filters of the query.
+    pub fn matches(&self, writings: &Writings) -> bool {
+        any_of(&self.types, &writings.ty())
+            && any_of(&self.authors, &writings.author())
+            && any_of(&self.works, &writings.work())
+            && self.matches_unindexed(writings)
+    }
+
+    /// The matching Writings, in document order.
+    pub fn execute(&self) -> Vec<Writings> {
+        let all = Writings::all();
+        self.positions()
+            .into_iter()
+            .map(|position| all[position].clone())
+            .collect()
+    }
+
+    /// The matching Writings converted to a specific type, e.g. [`crate::GleaningsParagraph`],
+    /// skipping Writings of any other type.
+    pub fn execute_as<T: TryFrom<Writings>>(&self) -> Vec<T> {
+        self.execute()
+            .into_iter()
+            .filter_map(|w| T::try_from(w).ok())
+            .collect()
+    }
+
+    /// The number of matching Writings.
+    pub fn count(&self) -> usize {
+        self.positions().len()
+    }
+
+    /// The positions of the matching Writings in `Writings::all()`, in ascending order.
+    pub fn positions(&self) -> Vec<usize> {
+        let index = CorpusIndex::get();
+        let all = Writings::all();
+
+        let candidates = [
+            index.lookup(&index.types, &self.types),
+            index.lookup(&index.authors, &self.authors),
+            index.lookup(&index.works, &self.works),
+            index.lookup(&index.prayer_kinds, &self.prayer_kinds),
+            index.lookup(&index.hidden_word_kinds, &self.hidden_word_kinds),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(|a, b| intersect(&a, &b));
+
+        match candidates {
+            Some(candidates) => candidates
+                .into_iter()
+                .filter(|&position| self.matches_unindexed(&all[position]))
+                .collect(),
+            None => (0..all.len())
+                .filter(|&position| self.matches_unindexed(&all[position]))
+                .collect(),
+        }
+    }
+
+    /// Whether the given Writings matches the filters that are not precomputed in the [`CorpusIndex`],
+    /// plus the kinds, which only apply to some types of Writings.
+    fn matches_unindexed(&self, writings: &Writings) -> bool {
+        let kinds_match = match writings {
+            Writings::Prayer(p) => any_of(&self.prayer_kinds, &p.kind),
+            Writings::HiddenWord(hw) => any_of(&self.hidden_word_kinds, &hw.kind),
+            _ => self.prayer_kinds.is_empty() && self.hidden_word_kinds.is_empty(),
+        };
+        if !kinds_match || !any_of(&self.styles, &writings.style()) {
+            return false;
+        }
+
+        if self
+            .numbers
+            .is_some_and(|range| !writings.number().is_some_and(|n| range.contains(&n)))
+        {
+            return false;
+        }
+
+        if self
+            .paragraphs
+            .is_some_and(|range| !range.contains(&writings.paragraph()))
+        {
+            return false;
+        }
+
+        if !self.sections.is_empty() {
+            let Writings::Prayer(prayer) = writings else {
+                return false;
+            };
+            let sections = prayer.section.iter().map(|s| fold(s)).collect::<Vec<_>>();
+            if !self
+                .sections
+                .iter()
+                .all(|section| sections.iter().any(|s| s.contains(section)))
+            {
+                return false;
+            }
+        }
+
+        if !self.texts.is_empty() {
+            let text = writings.text();
+            if !self.texts.iter().all(|predicate| (predicate.0)(&text)) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn any_of<T: PartialEq>(values: &[T], value: &T) -> bool {
+    values.is_empty() || values.contains(value)
+}
+
+/// Intersect two ascending lists of positions.
+fn intersect(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let (mut i, mut j) = (0, 0);
+    let mut result = Vec::with_capacity(a.len().min(b.len()));
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                result.push(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    result
+}
+
+/// Positions in `Writings::all()` of the Writings with each value of the indexed fields.
+#[derive(Debug, Default)]
+struct CorpusIndex {
+    types: HashMap<WritingsType, Vec<usize>>,
+    authors: HashMap<Author, Vec<usize>>,
+    works: HashMap<Work, Vec<usize>>,
+    prayer_kinds: HashMap<PrayerKind, Vec<usize>>,
+    hidden_word_kinds: HashMap<HiddenWordKind, Vec<usize>>,
+}
+
+impl CorpusIndex {
+    fn get() -> &'static CorpusIndex {
+        static INDEX: OnceLock<CorpusIndex> = OnceLock::new();
+        INDEX.get_or_init(|| {
+            let mut index = CorpusIndex::default();
+            for (position, writings) in Writings::all().iter().enumerate() {
+                index.types.entry(writings.ty()).or_default().push(position);
+                index
+                    .authors
+                    .entry(writings.author())
+                    .or_default()
+                    .push(position);
+                index
+                    .works
+                    .entry(writings.work())
+                    .or_default()
+                    .push(position);
+                match writings {
+                    Writings::Prayer(p) => index.prayer_kinds.entry(p.kind).or_default(),
+                    Writings::HiddenWord(hw) => index.hidden_word_kinds.entry(hw.kind).or_default(),
+                    _ => continue,
+                }
+                .push(position);
+            }
+            index
+        })
+    }
+
+    /// The ascending positions matching any of `values`, or `None` if there are no values to match.
+    fn lookup<K: Eq + Hash>(
+        &self,
+        map: &HashMap<K, Vec<usize>>,
+        values: &[K],
+    ) -> Option<Vec<usize>> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut positions = values
+            .iter()
+            .filter_map(|value| map.get(value))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        positions.sort_unstable();
+        positions.dedup();
+        Some(positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect() {
+        assert_eq!(intersect(&[1, 3, 5, 7], &[2, 3, 4, 7, 8]), vec![3, 7]);
+        assert!(intersect(&[1], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_query() {
+        let gleanings = Query::new()
+            .work(Work::Gleanings)
+            .number(11..=11)
+            .paragraph(3..)
+            .execute_as::<crate::GleaningsParagraph>();
+        assert!(!gleanings.is_empty());
+        assert!(gleanings.iter().all(|g| g.number == 11 && g.paragraph >= 3));
+
+        let query = Query::new()
+            .ty(WritingsType::HiddenWord)
+            .hidden_word_kind(HiddenWordKind::Persian);
+        assert_eq!(query.count(), 83);
+        assert!(query.execute().iter().all(|w| query.matches(w)));
+    }
+}
@@ -195,10 +195,11 @@ fn trimmed_text_with_citations_inner(
             continue;
         }
         if let Some(text) = child.value().as_text() {
+            let text = crate::normalize::nfc(text);
             let text = if position == 0 {
                 text.trim_start()
             } else {
-                text
+                &text
             };
             trimmed.push_str(text);
             position += text.len() as u32;
@@ -0,0 +1,253 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use rust_stemmers::{Algorithm, Stemmer};
+
+use super::search_engine::split_into_words;
+
+/// Reduces words to a canonical form, so that "believeth", "believest" and "believe",
+/// or "hath" and "has", match each other.
+///
+/// Applied to both the indexed text and the query, to words that are already
+/// lowercase and without diacritics:
+/// 1. Archaic pronouns and verbs are replaced with their modern forms, e.g. "thy" → "your",
+///    "art" → "are".
+/// 2. The archaic verb endings "-eth" and "-est" are removed from the known archaic verb forms,
+///    so that e.g. "greatest" and "Elizabeth" are unchanged.
+/// 3. The word is stemmed (Snowball English).
+/// 4. Synonyms are replaced with the first word of their group.
+///
+/// ```
+/// use writings::search::Analyzer;
+///
+/// let analyzer = Analyzer::default().with_synonyms(&["glory", "splendor"]);
+///
+/// assert_eq!(analyzer.analyze("believeth"), analyzer.analyze("believe"));
+/// assert_eq!(analyzer.analyze("lovest"), analyzer.analyze("love"));
+/// assert_eq!(analyzer.analyze("hath"), analyzer.analyze("has"));
+/// assert_eq!(analyzer.analyze("thy"), "your");
+/// assert_eq!(analyzer.analyze("splendor"), analyzer.analyze("glory"));
+/// ```
+pub struct Analyzer {
+    stemmer: Stemmer,
+    synonyms: HashMap<String, String>,
+}
+
+impl Default for Analyzer {
+    /// With synonyms for British and American spellings, e.g. "honour" and "honor".
+    fn default() -> Self {
+        SPELLINGS
+            .iter()
+            .fold(Self::new(), |analyzer, (british, american)| {
+                analyzer.with_synonyms(&[british, american])
+            })
+    }
+}
+
+impl std::fmt::Debug for Analyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Analyzer")
+            .field("synonyms", &self.synonyms)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Archaic forms and their modern equivalents.
+const ARCHAIC: &[(&str, &str)] = &[
+    ("thee", "you"),
+    ("thou", "you"),
+    ("ye", "you"),
+    ("thy", "your"),
+    ("thine", "your"),
+    ("thyself", "yourself"),
+    ("art", "are"),
+    ("wast", "was"),
+    ("wert", "were"),
+    ("hast", "have"),
+    ("hath", "has"),
+    ("hadst", "had"),
+    ("doth", "does"),
+    ("dost", "do"),
+    ("didst", "did"),
+    ("canst", "can"),
+    ("couldst", "could"),
+    ("shalt", "shall"),
+    ("shouldst", "should"),
+    ("wilt", "will"),
+    ("wouldst", "would"),
+    ("mayest", "may"),
+    ("mightest", "might"),
+    ("saith", "says"),
+    ("seeth", "sees"),
+    ("goeth", "goes"),
+    ("doeth", "does"),
+];
+
+/// The archaic verb forms ending in "-eth" or "-est" of the embedded Writings, and a few
+/// other common ones of the King James Bible, e.g. "sitteth".
+static ARCHAIC_VERBS: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("archaic_verbs.json"))
+        .unwrap_or_else(|err| panic!("Invalid archaic_verbs.json: {err}"))
+});
+
+const SPELLINGS: &[(&str, &str)] = &[
+    ("behaviour", "behavior"),
+    ("colour", "color"),
+    ("endeavour", "endeavor"),
+    ("favour", "favor"),
+    ("fervour", "fervor"),
+    ("honour", "honor"),
+    ("labour", "labor"),
+    ("splendour", "splendor"),
+    ("valour", "valor"),
+    ("vigour", "vigor"),
+];
+
+impl Analyzer {
+    /// Without any synonyms.
+    pub fn new() -> Self {
+        Self {
+            stemmer: Stemmer::create(Algorithm::English),
+            synonyms: HashMap::new(),
+        }
+    }
+
+    /// Treat all of the words as the first one.
+    pub fn with_synonyms(mut self, words: &[&str]) -> Self {
+        if let Some((first, rest)) = words.split_first() {
+            let canonical = self.analyze(first);
+            for word in rest {
+                let word = self.analyze(word);
+                if word != canonical {
+                    // Words that were synonyms of this one now share the new canonical form.
+                    for target in self.synonyms.values_mut() {
+                        if *target == word {
+                            target.clone_from(&canonical);
+                        }
+                    }
+                    self.synonyms.insert(word, canonical.clone());
+                }
+            }
+        }
+        self
+    }
+
+    /// The canonical form of a lowercase word without diacritics.
+    pub fn analyze(&self, word: &str) -> String {
+        let stem = match ARCHAIC.iter().find(|(archaic, _)| *archaic == word) {
+            Some((_, modern)) => (*modern).to_string(),
+            None => strip_archaic_ending(word),
+        };
+        self.synonym(self.stemmer.stem(&stem).into_owned())
+    }
+
+    /// The canonical forms of all words of the text, separated by spaces.
+    pub fn analyze_text(&self, text: &str) -> String {
+        split_into_words(text)
+            .iter()
+            .map(|word| self.analyze(word))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Each word with a synonym, and the canonical form it is replaced with.
+    pub(super) fn synonyms(&self) -> &HashMap<String, String> {
+        &self.synonyms
+    }
+
+    /// With the synonyms of another analyzer, see [`Analyzer::synonyms()`].
+    pub(super) fn with_synonym_map(synonyms: HashMap<String, String>) -> Self {
+        Self {
+            synonyms,
+            ..Self::new()
+        }
+    }
+
+    /// A fingerprint of the archaic forms and synonyms, which changes whenever the canonical
+    /// forms of words may, to rebuild a [`super::TantivyIndex`] saved on disk.
+    #[cfg(feature = "tantivy")]
+    pub(super) fn fingerprint(&self) -> u64 {
+        let mut archaic_verbs = ARCHAIC_VERBS.iter().collect::<Vec<_>>();
+        archaic_verbs.sort();
+        let mut synonyms = self.synonyms.iter().collect::<Vec<_>>();
+        synonyms.sort();
+        let words = ARCHAIC
+            .iter()
+            .flat_map(|(archaic, modern)| [*archaic, *modern])
+            .chain(archaic_verbs.into_iter().map(String::as_str))
+            .chain(
+                synonyms
+                    .into_iter()
+                    .flat_map(|(word, canonical)| [word.as_str(), canonical.as_str()]),
+            );
+        crate::embed_all::fnv1a(words.flat_map(|word| word.bytes().chain([0])))
+    }
+
+    fn synonym(&self, word: String) -> String {
+        self.synonyms.get(&word).cloned().unwrap_or(word)
+    }
+}
+
+/// Remove "-eth" or "-est" from a known archaic verb form, restoring the stem as best we can,
+/// e.g. "sitteth" → "sit", "loveth" → "love", "knoweth" → "know". Other words, e.g. the
+/// superlative "latest" or the name "Japheth", are unchanged.
+fn strip_archaic_ending(word: &str) -> String {
+    let Some(stem) = word
+        .strip_suffix("eth")
+        .or_else(|| word.strip_suffix("est"))
+        .filter(|_| ARCHAIC_VERBS.contains(word))
+    else {
+        return word.to_string();
+    };
+
+    let chars = stem.chars().collect::<Vec<_>>();
+    let is_vowel = |c: char| "aeiou".contains(c);
+    match chars.as_slice() {
+        // "sitteth" → "sit", but "falleth" → "fall".
+        [.., a, b] if a == b && !"lsz".contains(*a) && !is_vowel(*a) => {
+            stem[..stem.len() - 1].to_string()
+        }
+        // A short stem ending in consonant-vowel-consonant usually had a final "e",
+        // e.g. "lov" → "love", "giv" → "give".
+        [.., a, b, c]
+            if chars.len() <= 4 && !is_vowel(*a) && is_vowel(*b) && !"aeiouwxy".contains(*c) =>
+        {
+            format!("{stem}e")
+        }
+        _ => stem.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_archaic_ending() {
+        for (word, stem) in [
+            ("sitteth", "sit"),
+            ("falleth", "fall"),
+            ("loveth", "love"),
+            ("knowest", "know"),
+            ("believeth", "believ"),
+            ("manifest", "manifest"),
+            ("best", "best"),
+            ("dishonest", "dishonest"),
+            ("budapest", "budapest"),
+            ("elizabeth", "elizabeth"),
+            ("japheth", "japheth"),
+            ("latest", "latest"),
+        ] {
+            assert_eq!(strip_archaic_ending(word), stem, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_archaic_verbs() {
+        for word in ARCHAIC_VERBS.iter() {
+            assert!(word.ends_with("eth") || word.ends_with("est"), "{word}");
+            assert!(word.len() >= 6, "{word}");
+            assert!(ARCHAIC.iter().all(|(archaic, _)| archaic != word), "{word}");
+        }
+    }
+}
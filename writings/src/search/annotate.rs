@@ -0,0 +1,312 @@
+use std::{ops::Range, sync::LazyLock};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::{QuoteDiffKind, QuoteMatch, verify_quote};
+use crate::text::word_ranges;
+use crate::{WritingsTrait as _, sentence_ranges};
+
+/// Paragraphs (or other blocks) of a document: consecutive lines that are not blank.
+static BLOCK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)(?:^[^\S\n]*\S.*(?:\n|$))+").unwrap());
+
+/// Text in double quotation marks, curly or straight, within a block.
+static QUOTED_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"“[^“”]+”|"[^"]+""#).unwrap());
+
+/// The minimum number of words of a quotation to look for, e.g. not “the Word of God”.
+const MIN_QUOTED_WORDS: usize = 5;
+
+/// The minimum number of words of an unquoted sentence to look for.
+const MIN_SENTENCE_WORDS: usize = 8;
+
+/// The minimum [`QuoteMatch::similarity`] of a quotation to its source; quotations
+/// less similar are near-matches.
+const QUOTATION_SIMILARITY: f64 = 0.8;
+
+/// The minimum [`QuoteMatch::similarity`] of an unquoted sentence to be a near-match.
+const NEAR_MATCH_SIMILARITY: f64 = 0.7;
+
+/// The quotations of the Writings in a document, from [`annotate_quotes()`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct QuoteReport {
+    /// Passages in quotation marks or block quotes that are quotations of the Writings,
+    /// in document order.
+    pub quotations: Vec<QuoteAnnotation>,
+
+    /// Passages that are close to the Writings but not quoted as such, or quoted with
+    /// many differences, in document order.
+    pub near_matches: Vec<QuoteAnnotation>,
+}
+
+/// A passage of a document and its source in the Writings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct QuoteAnnotation {
+    /// The `char` offset of the passage in the document.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the passage.
+    pub end: usize,
+
+    /// The passage as in the document, including any quotation marks.
+    pub text: String,
+
+    /// The source, e.g. "The Hidden Words, Part One: From the Arabic, 1".
+    pub reference: String,
+
+    /// The source in the Bahá’í Reference Library: <https://www.bahai.org/r/`ref_id`>
+    pub url: String,
+
+    /// The source and how the passage differs from it.
+    pub source: QuoteMatch,
+}
+
+/// Find the quotations of all embedded Writings in a Markdown or plain text document.
+///
+/// Text in double quotation marks (“…” or "…") and block quotes (lines starting with `>`)
+/// are looked up with [`verify_quote()`], sentence by sentence if not found as a whole.
+/// Other sentences of the document that are close to the Writings are near-matches.
+///
+/// ```
+/// use writings::search::annotate_quotes;
+///
+/// let document = "As Bahá’u’lláh wrote, “The earth is but one country, and mankind its \
+///     citizens.”\n\nMy first counsel is this: possess a pure, kindly and radiant heart.";
+/// let report = annotate_quotes(document);
+///
+/// assert_eq!(report.quotations.len(), 1);
+/// assert_eq!(report.quotations[0].source.ref_id, "696472436");
+/// assert_eq!(report.near_matches.len(), 1);
+/// assert_eq!(report.near_matches[0].source.ref_id, "986635113");
+///
+/// let annotated = report.annotated(document, true);
+/// assert!(annotated.contains("citizens.”[^1]"));
+/// assert!(annotated.contains("[^1]: [Gleanings from the Writings of Bahá’u’lláh"));
+/// ```
+pub fn annotate_quotes(document: &str) -> QuoteReport {
+    let mut report = QuoteReport::default();
+    let mut quoted = vec![];
+    let blocks = BLOCK_REGEX
+        .find_iter(document)
+        .map(|m| m.range())
+        .collect::<Vec<_>>();
+
+    for block in &blocks {
+        let text = &document[block.clone()];
+        let block_quote = block.start..block.start + text.trim_end().len();
+        let spans = match text.lines().all(|line| line.trim_start().starts_with('>')) {
+            true => vec![block_quote],
+            false => QUOTED_REGEX
+                .find_iter(text)
+                .map(|m| block.start + m.start()..block.start + m.end())
+                .collect(),
+        };
+        for span in spans {
+            if word_count(&document[span.clone()]) < MIN_QUOTED_WORDS {
+                continue;
+            }
+            let (quotations, near_match) = find_quotations(document, span.clone());
+            report.quotations.extend(quotations);
+            report.near_matches.extend(near_match);
+            quoted.push(span);
+        }
+    }
+
+    for block in &blocks {
+        for sentence in sentence_ranges(&document[block.clone()]) {
+            let sentence = block.start + sentence.start..block.start + sentence.end;
+            if quoted
+                .iter()
+                .any(|span| span.start < sentence.end && sentence.start < span.end)
+                || word_count(&document[sentence.clone()]) < MIN_SENTENCE_WORDS
+            {
+                continue;
+            }
+            if let Some(found) = verify_quote(&document[sentence.clone()])
+                && found.similarity >= NEAR_MATCH_SIMILARITY
+            {
+                report
+                    .near_matches
+                    .push(QuoteAnnotation::new(document, sentence, found));
+            }
+        }
+    }
+    report.near_matches.sort_by_key(|a| a.start);
+    report
+}
+
+/// The quotations in a quoted span of the document: the whole span, or else its sentences;
+/// or a near-match if neither is similar enough to the source.
+fn find_quotations(
+    document: &str,
+    span: Range<usize>,
+) -> (Vec<QuoteAnnotation>, Option<QuoteAnnotation>) {
+    let found = verify_quote(&document[span.clone()]);
+    if let Some(found) = &found
+        && found.similarity >= QUOTATION_SIMILARITY
+    {
+        let annotation = QuoteAnnotation::new(document, span, found.clone());
+        return (vec![annotation], None);
+    }
+
+    let quotations = sentence_ranges(&document[span.clone()])
+        .into_iter()
+        .map(|sentence| span.start + sentence.start..span.start + sentence.end)
+        .filter(|sentence| word_count(&document[sentence.clone()]) >= MIN_QUOTED_WORDS)
+        .filter_map(|sentence| {
+            verify_quote(&document[sentence.clone()])
+                .filter(|found| found.similarity >= QUOTATION_SIMILARITY)
+                .map(|found| QuoteAnnotation::new(document, sentence, found))
+        })
+        .collect::<Vec<_>>();
+    match quotations.is_empty() {
+        true => (
+            vec![],
+            found.map(|f| QuoteAnnotation::new(document, span, f)),
+        ),
+        false => (quotations, None),
+    }
+}
+
+fn word_count(text: &str) -> usize {
+    word_ranges(text).count()
+}
+
+impl QuoteAnnotation {
+    fn new(document: &str, span: Range<usize>, source: QuoteMatch) -> Self {
+        let w = &source.item;
+        let mut reference = vec![w.work().title()];
+        reference.extend(w.subtitle());
+        reference.extend(w.number().map(|n| n.to_string()));
+        Self {
+            start: document[..span.start].chars().count(),
+            end: document[..span.end].chars().count(),
+            text: document[span].to_string(),
+            reference: reference.join(", "),
+            url: format!("https://www.bahai.org/r/{}", source.ref_id),
+            source,
+        }
+    }
+
+    /// The differences from the source, e.g. “kind” for “kindly”.
+    pub fn differences(&self) -> Vec<String> {
+        self.source
+            .diff
+            .iter()
+            .filter_map(|d| match d.kind {
+                QuoteDiffKind::Equal | QuoteDiffKind::Elided => None,
+                QuoteDiffKind::Changed | QuoteDiffKind::Replaced => {
+                    Some(format!("“{}” for “{}”", d.quote, d.source))
+                }
+                QuoteDiffKind::Added => Some(format!("“{}” added", d.quote)),
+                QuoteDiffKind::Missing => Some(format!("“{}” missing", d.source)),
+            })
+            .collect()
+    }
+}
+
+impl QuoteReport {
+    /// The document with a numbered note after each quotation and near-match, and the notes
+    /// with their references and links at the end: Markdown footnotes (`[^1]`) if `markdown`,
+    /// otherwise plain text (`[1]`).
+    pub fn annotated(&self, document: &str, markdown: bool) -> String {
+        let mut annotations = self
+            .quotations
+            .iter()
+            .map(|a| (a, true))
+            .chain(self.near_matches.iter().map(|a| (a, false)))
+            .collect::<Vec<_>>();
+        annotations.sort_by_key(|(a, _)| a.start);
+
+        let mut text = String::new();
+        let mut notes = vec![];
+        let mut chars = document.chars();
+        let mut offset = 0;
+        for (n, (annotation, quotation)) in annotations.into_iter().enumerate() {
+            let n = n + 1;
+            text.extend(chars.by_ref().take(annotation.end - offset));
+            offset = annotation.end;
+
+            let (marker, link) = match markdown {
+                true => (
+                    format!("[^{n}]"),
+                    format!("[{}]({})", annotation.reference, annotation.url),
+                ),
+                false => (
+                    format!("[{n}]"),
+                    format!("{} <{}>", annotation.reference, annotation.url),
+                ),
+            };
+            text.push_str(&marker);
+            let mut note = match quotation {
+                true => format!("{marker}{} {link}", if markdown { ":" } else { "" }),
+                false => format!("{marker}{} Compare {link}", if markdown { ":" } else { "" }),
+            };
+            let differences = annotation.differences();
+            if !differences.is_empty() {
+                note.push_str(&format!(", with differences: {}", differences.join("; ")));
+            }
+            notes.push(note);
+        }
+        text.extend(chars);
+
+        if notes.is_empty() {
+            return text;
+        }
+        format!("{}\n\n{}\n", text.trim_end(), notes.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotate_quotes() {
+        let document = "\
+# Unity
+
+Short quotations like “the Word of God” are not looked up.
+
+> My first counsel is this: Possess a pure, kindly and radiant heart, that thine may be a
+> sovereignty ancient, imperishable and everlasting.
+
+He said \"The earth is just one country and mankind its citizens.\" And a sentence about \
+cars and computers that has nothing to do with anything.
+";
+        let report = annotate_quotes(document);
+        let quotations = report
+            .quotations
+            .iter()
+            .map(|a| a.source.ref_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(quotations, ["986635113", "696472436"]);
+        assert!(report.near_matches.is_empty(), "{:?}", report.near_matches);
+
+        let quotation = &report.quotations[1];
+        assert_eq!(
+            document.chars().skip(quotation.start).collect::<String>()[..quotation.text.len()],
+            quotation.text
+        );
+        assert!(quotation.text.starts_with("\"The earth"));
+        assert_eq!(quotation.differences(), ["“just” for “but”"]);
+
+        let annotated = report.annotated(document, false);
+        assert!(annotated.contains("everlasting.[1]\n"));
+        assert!(annotated.contains("citizens.\"[2] And"));
+        assert!(annotated.ends_with(
+            "[2] Gleanings from the Writings of Bahá’u’lláh, 117 \
+             <https://www.bahai.org/r/696472436>, with differences: “just” for “but”\n"
+        ));
+        assert_eq!(
+            annotate_quotes("Nothing quoted here.").annotated("x", true),
+            "x"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use super::vocabulary::Vocabulary;
+use crate::text::{fold, words};
+use crate::{EmbedAllTrait as _, Writings, WritingsTrait as _};
+
+/// A suggested completion of a partial query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Completion {
+    /// The completed query: the query with its last word completed, or a whole title.
+    pub text: String,
+
+    pub kind: CompletionKind,
+
+    /// The number of paragraphs containing the word, or with the title.
+    pub count: usize,
+}
+
+/// Whether a [`Completion`] completes a word or is a title or invocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum CompletionKind {
+    Word,
+    /// A title, subtitle, prayer section, or invocation such as "O Son of Spirit".
+    Title,
+}
+
+/// The titles of all embedded Writings, for completing queries while typing,
+/// along with the words of the [`Vocabulary`].
+#[derive(Serialize, Deserialize)]
+pub(super) struct Completions {
+    titles: Vec<Title>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Title {
+    /// The folded words of the title.
+    words: Vec<String>,
+    text: String,
+    count: usize,
+}
+
+impl Completions {
+    pub(super) fn new() -> Self {
+        let mut titles: HashMap<String, usize> = HashMap::new();
+        for w in Writings::all().iter() {
+            let mut strings = vec![w.title()];
+            strings.extend(w.subtitle());
+            match w {
+                Writings::HiddenWord(hw) => strings.extend(hw.invocation.clone()),
+                Writings::Prayer(prayer) => strings.extend(prayer.section.iter().cloned()),
+                _ => {}
+            }
+            strings.dedup();
+            for title in strings {
+                let title = title.trim_end_matches(|c: char| !c.is_alphanumeric());
+                if !title.is_empty() {
+                    *titles.entry(title.to_string()).or_default() += 1;
+                }
+            }
+        }
+
+        let mut titles = titles
+            .into_iter()
+            .map(|(text, count)| Title {
+                words: words(&text).map(fold).collect(),
+                text,
+                count,
+            })
+            .collect::<Vec<_>>();
+        titles.sort_by(|a, b| a.text.cmp(&b.text));
+
+        Self { titles }
+    }
+
+    /// Up to `limit` completions of the query, best first, ignoring case and diacritics.
+    ///
+    /// Titles containing the query come first when the query has several words; a single
+    /// word is completed first as typed, then by titles it starts, then by longer words.
+    /// Within each, completions are ranked by how often they occur.
+    pub(super) fn complete(
+        &self,
+        vocabulary: &Vocabulary,
+        query: &str,
+        limit: usize,
+    ) -> Vec<Completion> {
+        let typed = words(query).collect::<Vec<_>>();
+        let folded = typed.iter().map(|w| fold(w)).collect::<Vec<_>>();
+        let Some(last) = folded.last() else {
+            return vec![];
+        };
+        // After a space, the last word is complete and only titles can complete the query.
+        let complete = query.ends_with(char::is_whitespace);
+
+        let mut candidates = vec![];
+
+        for title in &self.titles {
+            let Some(position) = title.words.windows(folded.len()).position(|window| {
+                window[..folded.len() - 1] == folded[..folded.len() - 1]
+                    && if complete {
+                        window[folded.len() - 1] == *last
+                    } else {
+                        window[folded.len() - 1].starts_with(last.as_str())
+                    }
+            }) else {
+                continue;
+            };
+            // A single word only completes titles it starts, e.g. not "Bahá’ís" in
+            // "Revealed to the Bahá’ís of Canada".
+            if (complete && position + folded.len() == title.words.len())
+                || (folded.len() == 1 && position != 0)
+            {
+                continue;
+            }
+            let rank = (folded.len() == 1) as u8;
+            candidates.push((rank, title.count, CompletionKind::Title, title.text.clone()));
+        }
+
+        if !complete && !last.is_empty() {
+            let prefix = &query[..query.rfind(typed[typed.len() - 1]).unwrap_or_default()];
+            for (word, (spelling, count)) in vocabulary.prefixed(last) {
+                let rank = (word != last) as u8;
+                let text = format!("{prefix}{spelling}");
+                candidates.push((rank, *count, CompletionKind::Word, text));
+            }
+        }
+
+        candidates.sort_by_key(|(rank, count, kind, text)| {
+            (
+                *rank,
+                *kind == CompletionKind::Word,
+                Reverse(*count),
+                text.clone(),
+            )
+        });
+        let mut completions: Vec<Completion> = vec![];
+        for (_, count, kind, text) in candidates {
+            if completions.len() == limit {
+                break;
+            }
+            if !completions
+                .iter()
+                .any(|c| c.text.to_lowercase() == text.to_lowercase())
+            {
+                completions.push(Completion { text, kind, count });
+            }
+        }
+        completions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete() {
+        let vocabulary = Vocabulary::new();
+        let completions = Completions::new();
+        let texts = |query| {
+            completions
+                .complete(&vocabulary, query, 9)
+                .into_iter()
+                .map(|c| c.text)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(texts("baha").contains(&"Bahá’u’lláh".to_string()));
+        assert_eq!(texts("son of spi")[0], "O Son of Spirit");
+        assert!(
+            texts("o son ")
+                .iter()
+                .all(|text| text.starts_with("O Son of"))
+        );
+        assert!(texts("mercy jus").contains(&"mercy justice".to_string()));
+        assert!(texts("").is_empty());
+    }
+}
@@ -0,0 +1,256 @@
+use std::{collections::HashMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use super::Analyzer;
+use crate::text::{fold, possessive_len, word_ranges};
+use crate::{EmbedAllTrait as _, Writings, WritingsTrait as _};
+
+/// One occurrence of a word in the text of a paragraph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Occurrence {
+    /// The reference ID of the paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    /// The position of the paragraph in `Writings::all()`.
+    pub position: usize,
+
+    /// The `char` offset of the word in the `text` of the paragraph.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the word.
+    pub end: usize,
+}
+
+/// A keyword in context: an [`Occurrence`] with the words before and after it.
+///
+/// Displays as a concordance line, with the keyword in a column when the width is given,
+/// e.g. `format!("{kwic:40}")`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Kwic {
+    pub ref_id: String,
+    pub left: String,
+    /// The word as written in the text.
+    pub keyword: String,
+    pub right: String,
+}
+
+/// Every occurrence of every word in the text of all embedded Writings, in document order,
+/// by word (ignoring case, diacritics, apostrophes and a possessive "’s") or by lemma ("detached" and
+/// "detachment" share the lemma of the [`Analyzer`]).
+///
+/// ```
+/// use writings::search::{Analyzer, Concordance};
+///
+/// let concordance = Concordance::new(&Analyzer::default());
+/// let words = concordance.occurrences("detachment", false);
+/// let lemmas = concordance.occurrences("detachment", true);
+///
+/// assert!(!words.is_empty());
+/// assert!(lemmas.len() > words.len());
+///
+/// let kwic = words[0].kwic(3);
+/// assert_eq!(kwic.keyword.to_lowercase(), "detachment");
+/// ```
+pub struct Concordance {
+    /// The occurrences of each folded word.
+    words: HashMap<String, Vec<Occurrence>>,
+
+    /// The folded words of each lemma.
+    lemmas: HashMap<String, Vec<String>>,
+
+    analyzer: Analyzer,
+}
+
+impl fmt::Debug for Concordance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Concordance")
+            .field("words", &self.words.len())
+            .field("lemmas", &self.lemmas.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Concordance {
+    /// Index the text of all embedded Writings, with lemmas from the analyzer.
+    pub fn new(analyzer: &Analyzer) -> Self {
+        let mut words: HashMap<String, Vec<Occurrence>> = HashMap::new();
+        for (position, w) in Writings::all().iter().enumerate() {
+            let text = w.text();
+            let mut chars = 0;
+            let mut bytes = 0;
+            for range in word_ranges(&text) {
+                // "God’s" is an occurrence of "God".
+                let range = range.start..range.end - possessive_len(&text[range.clone()]);
+                chars += text[bytes..range.start].chars().count();
+                let start = chars;
+                chars += text[range.clone()].chars().count();
+                bytes = range.end;
+
+                let folded = fold(&text[range]);
+                if folded.is_empty() {
+                    continue;
+                }
+                words.entry(folded).or_default().push(Occurrence {
+                    ref_id: w.ref_id(),
+                    position,
+                    start,
+                    end: chars,
+                });
+            }
+        }
+
+        let mut lemmas: HashMap<String, Vec<String>> = HashMap::new();
+        for word in words.keys() {
+            lemmas
+                .entry(analyzer.analyze(word))
+                .or_default()
+                .push(word.clone());
+        }
+        for forms in lemmas.values_mut() {
+            forms.sort();
+        }
+
+        Self {
+            words,
+            lemmas,
+            analyzer: Analyzer::with_synonym_map(analyzer.synonyms().clone()),
+        }
+    }
+
+    /// Every occurrence of the word in document order, ignoring case, diacritics,
+    /// apostrophes and a possessive "’s"; with `lemma`, also of the other forms of the word.
+    pub fn occurrences(&self, word: &str, lemma: bool) -> Vec<&Occurrence> {
+        let folded = fold(&word[..word.len() - possessive_len(word)]);
+        if !lemma {
+            return self.words.get(&folded).into_iter().flatten().collect();
+        }
+        let mut occurrences = self
+            .forms(&folded)
+            .iter()
+            .flat_map(|form| &self.words[form])
+            .collect::<Vec<_>>();
+        occurrences.sort_by_key(|o| (o.position, o.start));
+        occurrences
+    }
+
+    /// The folded forms of the word that share its lemma, including the word itself
+    /// if it occurs.
+    pub fn forms(&self, word: &str) -> &[String] {
+        self.lemmas
+            .get(&self.analyzer.analyze(&fold(word)))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+impl Occurrence {
+    /// The occurrence with up to `n` words of context on either side, within its paragraph.
+    pub fn kwic(&self, n: usize) -> Kwic {
+        let text = Writings::all()[self.position].text();
+        let byte = |chars: usize| {
+            text.char_indices()
+                .nth(chars)
+                .map_or(text.len(), |(byte, _)| byte)
+        };
+        let (start, end) = (byte(self.start), byte(self.end));
+
+        let before = word_ranges(&text[..start]).collect::<Vec<_>>();
+        let left_start = before
+            .len()
+            .checked_sub(n)
+            .and_then(|i| before.get(i))
+            .map_or(0, |range| range.start);
+        let left_start = if n == 0 { start } else { left_start };
+        let right_end = word_ranges(&text[end..])
+            .take(n)
+            .last()
+            .map_or(end, |range| end + range.end);
+        let right_end = if n == 0 { end } else { right_end };
+
+        Kwic {
+            ref_id: self.ref_id.clone(),
+            left: text[left_start..start].trim_start().to_string(),
+            keyword: text[start..end].to_string(),
+            right: text[end..right_end].trim_end().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Kwic {
+    /// The left context right-aligned (and cut) to the width, if given.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let left = match f.width() {
+            Some(width) => {
+                let chars = self.left.chars().count();
+                let left = self.left.chars().skip(chars.saturating_sub(width));
+                format!("{:>width$}", left.collect::<String>())
+            }
+            None => self.left.clone(),
+        };
+        write!(f, "{left}{}{}", self.keyword, self.right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kwic() {
+        let concordance = Concordance::new(&Analyzer::default());
+        let occurrences = concordance.occurrences("Bahaullah", false);
+        assert!(
+            occurrences
+                .windows(2)
+                .all(|o| o[0].position <= o[1].position)
+        );
+
+        let occurrence = occurrences[0];
+        let text = Writings::all()[occurrence.position].text();
+        let kwic = occurrence.kwic(2);
+        assert_eq!(kwic.keyword, "Bahá’u’lláh");
+        assert!(text.contains(&kwic.to_string()));
+        assert!(kwic.left.split_whitespace().count() <= 2);
+        assert!(kwic.right.split_whitespace().count() <= 2);
+        assert_eq!(
+            format!("{kwic:40}").chars().count(),
+            40 + kwic.keyword.chars().count() + kwic.right.chars().count()
+        );
+
+        let kwic = occurrence.kwic(0);
+        assert_eq!((kwic.left.as_str(), kwic.right.as_str()), ("", ""));
+    }
+
+    #[test]
+    fn test_possessives() {
+        let concordance = Concordance::new(&Analyzer::default());
+        let god = concordance.occurrences("God", false);
+        assert_eq!(concordance.occurrences("God’s", false), god);
+        let possessive = god
+            .iter()
+            .find(|o| {
+                let text = Writings::all()[o.position].text();
+                text.chars().skip(o.end).take(2).collect::<String>() == "’s"
+            })
+            .expect("an occurrence of “God’s”");
+        assert_eq!(possessive.kwic(0).keyword, "God");
+        assert!(concordance.occurrences("Bahá’u’lláh’s", false).len() > 1);
+    }
+
+    #[test]
+    fn test_forms() {
+        let concordance = Concordance::new(&Analyzer::default());
+        let forms = concordance.forms("detached");
+        assert!(forms.contains(&"detachment".to_string()));
+        assert!(forms.contains(&"detached".to_string()));
+        assert!(concordance.forms("xyzzy").is_empty());
+    }
+}
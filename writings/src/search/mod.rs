@@ -0,0 +1,41 @@
+#![cfg(feature = "search")]
+//! Ranked full-text search over all embedded Writings.
+//!
+//! ```
+//! use writings::search::SearchEngine;
+//!
+//! let engine = SearchEngine::default();
+//! let results = engine.search("all things visible and invisible").unwrap();
+//!
+//! assert!(results[0].excerpt.contains("visible and invisible"));
+//! assert!(results.windows(2).all(|r| r[0].score >= r[1].score));
+//! ```
+
+mod analyzer;
+pub use analyzer::Analyzer;
+mod annotate;
+pub use annotate::{QuoteAnnotation, QuoteReport, annotate_quotes};
+mod autocomplete;
+pub use autocomplete::{Completion, CompletionKind};
+mod concordance;
+pub use concordance::{Concordance, Kwic, Occurrence};
+mod quote;
+pub use quote::{QuoteDiff, QuoteDiffKind, QuoteMatch, verify_quote};
+mod related;
+pub use related::{RelatedIndex, RelatedResult};
+mod search_engine;
+pub use search_engine::SearchEngine;
+mod search_facets;
+pub use search_facets::{AuthorCount, PrayerKindCount, SearchFacets, TypeCount, WorkCount};
+mod search_match;
+pub use search_match::{MatchSpan, ScoreExplanation};
+mod search_query;
+pub use search_query::{SearchFilter, SearchQuery, SearchTerm};
+mod search_result;
+pub use search_result::SearchResult;
+mod search_weights;
+pub use search_weights::SearchWeights;
+mod tantivy_index;
+#[cfg(feature = "tantivy")]
+pub use tantivy_index::TantivyIndex;
+mod vocabulary;
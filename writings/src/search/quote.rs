@@ -0,0 +1,469 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+    sync::LazyLock,
+};
+
+use rapidfuzz::distance::levenshtein;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::text::{fold, word_ranges};
+use crate::{EmbedAllTrait as _, Writings, WritingsTrait as _};
+
+/// An ellipsis marking an elision: "…", or three dots, possibly spaced.
+static ELLIPSIS_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"…|\.\s?\.\s?\.").unwrap());
+
+/// The words of all embedded Writings, and the paragraphs containing each word.
+static CORPUS: LazyLock<Corpus> = LazyLock::new(Corpus::new);
+
+/// The number of paragraphs sharing the most (rare) words with a quotation to align it with.
+const MAX_CANDIDATES: usize = 10;
+
+/// The minimum [`QuoteMatch::similarity`] of a quotation and its source.
+const MIN_SIMILARITY: f64 = 0.5;
+
+/// The minimum similarity (0.0 to 1.0) of a misspelled word and its source.
+const MIN_WORD_SIMILARITY: f64 = 0.75;
+
+/// The minimum length of a word that another is a form of, e.g. "kind" of "kindly".
+const MIN_STEM: usize = 4;
+
+const EQUAL: i32 = 2;
+const CHANGED: i32 = 1;
+const MISMATCH: i32 = -1;
+const GAP: i32 = -1;
+
+/// The source of a quotation, from [`verify_quote()`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct QuoteMatch {
+    /// The reference ID of the source paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    /// Whether the quotation is word for word as in the source, ignoring case, punctuation,
+    /// quotation marks and diacritics, apart from any elisions marked with "…".
+    pub exact: bool,
+
+    /// The share of words that are the same in the quotation and the source,
+    /// from 0.0 to 1.0.
+    pub similarity: f64,
+
+    /// The quoted text of the source, including any elided words.
+    pub source: String,
+
+    /// The `char` offset of the `source` in the `text` of the `item`.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the `source`.
+    pub end: usize,
+
+    /// The words of the quotation compared with the source, in order.
+    pub diff: Vec<QuoteDiff>,
+
+    /// The source paragraph.
+    pub item: Writings,
+}
+
+/// Consecutive words of a quotation and the corresponding words of its source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct QuoteDiff {
+    pub kind: QuoteDiffKind,
+
+    /// The words of the quotation, or empty if `missing` or `elided`.
+    pub quote: String,
+
+    /// The words of the source, or empty if `added`.
+    pub source: String,
+}
+
+/// How words of a quotation differ from its source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum QuoteDiffKind {
+    /// The same words, ignoring case, punctuation and diacritics.
+    Equal,
+    /// Misspelled words or other forms of the same words, e.g. "mercifull" for "merciful",
+    /// or "kind" for "kindly".
+    Changed,
+    /// Different words, e.g. "you" for "thee".
+    Replaced,
+    /// Words of the quotation that are not in the source.
+    Added,
+    /// Words of the source left out of the quotation without an ellipsis.
+    Missing,
+    /// Words of the source left out of the quotation with an ellipsis.
+    Elided,
+}
+
+/// Find the source of a quotation in all embedded Writings, and how it differs.
+///
+/// The quotation may differ from its source in case, punctuation, quotation marks and
+/// diacritics, and may leave words out with an ellipsis ("…" or "..."). Returns `None`
+/// if less than half of the words match any one paragraph.
+///
+/// ```
+/// use writings::search::{QuoteDiffKind, verify_quote};
+///
+/// let found = verify_quote("“My first counsel is this: Possess a pure, kindly and radiant \
+///     heart...”").unwrap();
+/// assert_eq!(found.ref_id, "986635113");
+/// assert!(found.exact);
+///
+/// let misquoted = verify_quote("Possess a pure, kind and radiant heart").unwrap();
+/// assert!(!misquoted.exact);
+/// assert!(misquoted.diff.iter().any(|d| d.kind == QuoteDiffKind::Changed
+///     && d.quote == "kind" && d.source == "kindly"));
+/// ```
+pub fn verify_quote(text: &str) -> Option<QuoteMatch> {
+    let quote = tokenize_quote(text);
+    let words = quote
+        .iter()
+        .filter_map(|token| match token {
+            QuoteToken::Word(_, folded) => Some(folded.as_str()),
+            QuoteToken::Ellipsis => None,
+        })
+        .collect::<Vec<_>>();
+    if words.is_empty() {
+        return None;
+    }
+
+    CORPUS
+        .candidates(&words)
+        .into_iter()
+        .filter_map(|position| align(text, &quote, position))
+        .filter(|m| m.similarity >= MIN_SIMILARITY)
+        .max_by(|a, b| a.similarity.total_cmp(&b.similarity))
+}
+
+struct Corpus {
+    /// The byte ranges and folded forms of the words of each paragraph.
+    paragraphs: Vec<Vec<(Range<usize>, String)>>,
+
+    /// The positions of the paragraphs containing each folded word.
+    postings: HashMap<String, Vec<usize>>,
+}
+
+impl Corpus {
+    fn new() -> Self {
+        let paragraphs = Writings::all()
+            .iter()
+            .map(|w| {
+                let text = w.text();
+                word_ranges(&text)
+                    .map(|range| {
+                        let folded = fold(&text[range.clone()]);
+                        (range, folded)
+                    })
+                    .filter(|(_, folded)| !folded.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut postings: HashMap<String, Vec<usize>> = HashMap::new();
+        for (position, words) in paragraphs.iter().enumerate() {
+            let unique = words.iter().map(|(_, w)| w).collect::<HashSet<_>>();
+            for word in unique {
+                postings.entry(word.clone()).or_default().push(position);
+            }
+        }
+        Self {
+            paragraphs,
+            postings,
+        }
+    }
+
+    /// The positions of the paragraphs sharing the most words with the quotation,
+    /// weighted by how rare they are.
+    fn candidates(&self, words: &[&str]) -> Vec<usize> {
+        let total = self.paragraphs.len() as f64;
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        for word in words.iter().collect::<HashSet<_>>() {
+            let Some(positions) = self.postings.get(*word) else {
+                continue;
+            };
+            let idf = (total / positions.len() as f64).ln();
+            for &position in positions {
+                *scores.entry(position).or_default() += idf;
+            }
+        }
+        let mut scores = scores.into_iter().collect::<Vec<_>>();
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        scores
+            .into_iter()
+            .take(MAX_CANDIDATES)
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
+
+enum QuoteToken {
+    /// The byte range and folded form of a word of the quotation.
+    Word(Range<usize>, String),
+    Ellipsis,
+}
+
+fn tokenize_quote(text: &str) -> Vec<QuoteToken> {
+    let mut tokens = vec![];
+    let mut start = 0;
+    let ellipses = ELLIPSIS_REGEX
+        .find_iter(text)
+        .map(|m| m.range())
+        .chain(std::iter::once(text.len()..text.len()));
+    for ellipsis in ellipses {
+        for range in word_ranges(&text[start..ellipsis.start]) {
+            let range = start + range.start..start + range.end;
+            let folded = fold(&text[range.clone()]);
+            if !folded.is_empty() {
+                tokens.push(QuoteToken::Word(range, folded));
+            }
+        }
+        if !ellipsis.is_empty() {
+            tokens.push(QuoteToken::Ellipsis);
+        }
+        start = ellipsis.end;
+    }
+    tokens
+}
+
+/// A step of the alignment of the quotation with the source.
+#[derive(Clone, Copy)]
+enum Step {
+    /// The quotation word with the source word (equal, changed or replaced).
+    Pair,
+    /// A quotation word not in the source.
+    Added,
+    /// A source word not in the quotation.
+    Missing,
+    /// An ellipsis, skipping from the given source word.
+    Elided(usize),
+}
+
+/// Align all of the quotation with the span of the paragraph it matches best:
+/// a word-level alignment that is global in the quotation and local in the source,
+/// where ellipses skip any number of source words.
+fn align(text: &str, quote: &[QuoteToken], position: usize) -> Option<QuoteMatch> {
+    let source = &CORPUS.paragraphs[position];
+    let (n, m) = (quote.len(), source.len());
+    let mut scores = vec![vec![0i32; m + 1]; n + 1];
+    let mut steps = vec![vec![Step::Missing; m + 1]; n + 1];
+
+    for i in 1..=n {
+        match &quote[i - 1] {
+            QuoteToken::Ellipsis => {
+                let mut best = 0;
+                for j in 0..=m {
+                    if scores[i - 1][j] > scores[i - 1][best] {
+                        best = j;
+                    }
+                    scores[i][j] = scores[i - 1][best];
+                    steps[i][j] = Step::Elided(best);
+                }
+            }
+            QuoteToken::Word(_, word) => {
+                scores[i][0] = scores[i - 1][0] + GAP;
+                steps[i][0] = Step::Added;
+                for j in 1..=m {
+                    let pair = scores[i - 1][j - 1] + compare(word, &source[j - 1].1).1;
+                    let added = scores[i - 1][j] + GAP;
+                    let missing = scores[i][j - 1] + GAP;
+                    (scores[i][j], steps[i][j]) = if pair >= added && pair >= missing {
+                        (pair, Step::Pair)
+                    } else if added >= missing {
+                        (added, Step::Added)
+                    } else {
+                        (missing, Step::Missing)
+                    };
+                }
+            }
+        }
+    }
+
+    let end = (0..=m).max_by_key(|&j| (scores[n][j], std::cmp::Reverse(j)))?;
+    let w = Writings::all()[position].clone();
+    let source_text = w.text();
+    let source_word = |j: usize| &source_text[source[j].0.clone()];
+
+    // Trace back from the end, collecting each step (and its words) in reverse.
+    let mut diff: ReverseDiff = vec![];
+    let mut push = |kind, quote, source| push_reversed(&mut diff, kind, quote, source);
+    let (mut i, mut j) = (n, end);
+    let mut start = end;
+    while i > 0 {
+        match (&quote[i - 1], steps[i][j]) {
+            (QuoteToken::Ellipsis, Step::Elided(from)) => {
+                push(
+                    QuoteDiffKind::Elided,
+                    None,
+                    (from..j).map(source_word).collect(),
+                );
+                j = from;
+                i -= 1;
+            }
+            (QuoteToken::Word(range, word), Step::Pair) => {
+                let kind = compare(word, &source[j - 1].1).0;
+                push(kind, Some(&text[range.clone()]), vec![source_word(j - 1)]);
+                i -= 1;
+                j -= 1;
+                start = j;
+            }
+            (QuoteToken::Word(range, _), Step::Added) => {
+                push(QuoteDiffKind::Added, Some(&text[range.clone()]), vec![]);
+                i -= 1;
+            }
+            (_, _) => {
+                push(QuoteDiffKind::Missing, None, vec![source_word(j - 1)]);
+                j -= 1;
+                start = j;
+            }
+        }
+    }
+    if start >= end {
+        return None;
+    }
+
+    let diff = diff
+        .into_iter()
+        .rev()
+        .filter(|(kind, _, source)| *kind != QuoteDiffKind::Elided || !source.is_empty())
+        .map(|(kind, quote, source)| QuoteDiff {
+            kind,
+            quote: quote.into_iter().rev().collect::<Vec<_>>().join(" "),
+            source: source.into_iter().rev().collect::<Vec<_>>().join(" "),
+        })
+        .collect::<Vec<_>>();
+
+    let count = |kinds: &[QuoteDiffKind], quote: bool| {
+        diff.iter()
+            .filter(|d| kinds.contains(&d.kind))
+            .map(|d| match quote {
+                true => d.quote.split_whitespace().count(),
+                false => d.source.split_whitespace().count(),
+            })
+            .sum::<usize>()
+    };
+    use QuoteDiffKind::*;
+    let equal = count(&[Equal], true);
+    let quoted = count(&[Equal, Changed, Replaced, Added], true);
+    let sourced = count(&[Equal, Changed, Replaced, Missing], false);
+
+    let byte_range = source[start].0.start..source[end - 1].0.end;
+    let chars = |byte: usize| source_text[..byte].chars().count();
+    Some(QuoteMatch {
+        ref_id: w.ref_id(),
+        exact: diff.iter().all(|d| matches!(d.kind, Equal | Elided)),
+        similarity: equal as f64 / quoted.max(sourced).max(1) as f64,
+        source: source_text[byte_range.clone()].to_string(),
+        start: chars(byte_range.start),
+        end: chars(byte_range.end),
+        diff,
+        item: w,
+    })
+}
+
+/// Diff entries with their quotation and source words, all in reverse order.
+type ReverseDiff<'a> = Vec<(QuoteDiffKind, Vec<&'a str>, Vec<&'a str>)>;
+
+/// Add the step to the last entry of the same kind (except elisions), or a new entry.
+fn push_reversed<'a>(
+    diff: &mut ReverseDiff<'a>,
+    kind: QuoteDiffKind,
+    quote: Option<&'a str>,
+    source: Vec<&'a str>,
+) {
+    match diff.last_mut() {
+        Some((last, q, s)) if *last == kind && kind != QuoteDiffKind::Elided => {
+            q.extend(quote);
+            s.extend(source.into_iter().rev());
+        }
+        _ => diff.push((
+            kind,
+            quote.into_iter().collect(),
+            source.into_iter().rev().collect(),
+        )),
+    }
+}
+
+/// How a folded word of a quotation compares with a folded word of the source,
+/// and the score of aligning them.
+fn compare(quote: &str, source: &str) -> (QuoteDiffKind, i32) {
+    if quote == source {
+        (QuoteDiffKind::Equal, EQUAL)
+    } else if levenshtein::normalized_similarity(quote.chars(), source.chars())
+        >= MIN_WORD_SIMILARITY
+        || is_other_form(quote, source)
+    {
+        (QuoteDiffKind::Changed, CHANGED)
+    } else {
+        (QuoteDiffKind::Replaced, MISMATCH)
+    }
+}
+
+/// Whether one word is the other with a suffix, e.g. "kindly" and "kind".
+fn is_other_form(a: &str, b: &str) -> bool {
+    let (shorter, longer) = if a.len() < b.len() { (a, b) } else { (b, a) };
+    shorter.len() >= MIN_STEM && longer.starts_with(shorter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(m: &QuoteMatch) -> Vec<(QuoteDiffKind, &str, &str)> {
+        m.diff
+            .iter()
+            .map(|d| (d.kind, d.quote.as_str(), d.source.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_quote() {
+        use QuoteDiffKind::*;
+
+        let m = verify_quote("the earth is but one country and mankind its citizens").unwrap();
+        assert_eq!(m.ref_id, "696472436");
+        assert!(m.exact);
+        assert_eq!(
+            m.source,
+            "The earth is but one country, and mankind its citizens"
+        );
+
+        let m = verify_quote(
+            "\"My first counsel is this: Possess a pure, kindly and radiant heart, \
+             that thine may be a sovereignty … imperishable and everlasting.\"",
+        )
+        .unwrap();
+        assert_eq!(m.ref_id, "986635113");
+        assert!(m.exact, "{:?}", diff(&m));
+        assert_eq!(m.similarity, 1.0);
+        assert!(m.source.starts_with("My first counsel"));
+        assert!(
+            m.item
+                .text()
+                .chars()
+                .skip(m.start)
+                .collect::<String>()
+                .starts_with(&m.source)
+        );
+        assert!(diff(&m).contains(&(Elided, "", "ancient")));
+
+        let m = verify_quote(
+            "My first counsel is this: possess a pure and radiant heart, that you may be a \
+             sovereignty ancient, imperishable and everlasting",
+        )
+        .unwrap();
+        assert_eq!(m.ref_id, "986635113");
+        assert!(!m.exact);
+        let diff = diff(&m);
+        assert!(diff.contains(&(Missing, "", "kindly")), "{diff:?}");
+        assert!(diff.contains(&(Replaced, "you", "thine")), "{diff:?}");
+    }
+}
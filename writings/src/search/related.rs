@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Analyzer;
+use super::search_engine::split_into_words;
+use crate::{EmbedAllTrait as _, Writings, WritingsTrait as _};
+
+/// BM25 term frequency saturation.
+const K1: f32 = 1.2;
+
+/// BM25 document length normalization.
+const B: f32 = 0.75;
+
+/// Words in more than this share of paragraphs (e.g. "the", "God") say little about
+/// what a paragraph is about, and are ignored.
+const MAX_DOCUMENT_FREQUENCY: f32 = 0.2;
+
+/// A paragraph similar to another, from [`RelatedIndex::related()`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct RelatedResult {
+    /// The reference ID of the similar paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    /// The cosine similarity of the two paragraphs, from 0.0 (nothing in common) to 1.0.
+    pub score: f64,
+
+    /// The similar paragraph.
+    pub item: Writings,
+}
+
+/// "More like this": the paragraphs of all embedded Writings as BM25-weighted vectors of
+/// their (analyzed) words, to find the paragraphs most similar to any one of them.
+///
+/// ```
+/// use writings::search::{Analyzer, RelatedIndex};
+///
+/// let index = RelatedIndex::new(&Analyzer::default());
+/// let related = index.related("646181142", 5, false).unwrap();
+///
+/// assert_eq!(related.len(), 5);
+/// assert!(related.iter().all(|r| r.ref_id != "646181142"));
+/// assert!(related.windows(2).all(|r| r[0].score >= r[1].score));
+/// ```
+pub struct RelatedIndex {
+    /// The normalized weight of each term of each paragraph, by position in `Writings::all()`.
+    vectors: Vec<Vec<(usize, f32)>>,
+
+    /// The paragraphs containing each term, with its weight in each.
+    postings: Vec<Vec<(usize, f32)>>,
+}
+
+impl std::fmt::Debug for RelatedIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RelatedIndex")
+            .field("paragraphs", &self.vectors.len())
+            .field("terms", &self.postings.len())
+            .finish()
+    }
+}
+
+impl RelatedIndex {
+    /// Index the text of all embedded Writings, with the words reduced by the analyzer.
+    pub fn new(analyzer: &Analyzer) -> Self {
+        let mut term_ids: HashMap<String, usize> = HashMap::new();
+        let counts = Writings::all()
+            .iter()
+            .map(|w| {
+                let mut counts: HashMap<usize, f32> = HashMap::new();
+                for word in split_into_words(&w.text()) {
+                    let next_id = term_ids.len();
+                    let id = *term_ids.entry(analyzer.analyze(&word)).or_insert(next_id);
+                    *counts.entry(id).or_default() += 1.0;
+                }
+                counts
+            })
+            .collect::<Vec<_>>();
+
+        let mut document_frequencies = vec![0usize; term_ids.len()];
+        for counts in &counts {
+            for &id in counts.keys() {
+                document_frequencies[id] += 1;
+            }
+        }
+        let paragraphs = counts.len() as f32;
+        let average_length = counts
+            .iter()
+            .map(|counts| counts.values().sum::<f32>())
+            .sum::<f32>()
+            / paragraphs.max(1.0);
+        let idf = document_frequencies
+            .iter()
+            .map(|&df| {
+                let df = df as f32;
+                match df / paragraphs > MAX_DOCUMENT_FREQUENCY {
+                    true => 0.0,
+                    false => (1.0 + (paragraphs - df + 0.5) / (df + 0.5)).ln(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut postings = vec![vec![]; term_ids.len()];
+        let vectors = counts
+            .into_iter()
+            .enumerate()
+            .map(|(position, counts)| {
+                let length = counts.values().sum::<f32>();
+                let norm = K1 * (1.0 - B + B * length / average_length);
+                let mut vector = counts
+                    .into_iter()
+                    .map(|(id, tf)| (id, idf[id] * tf * (K1 + 1.0) / (tf + norm)))
+                    .filter(|(_, weight)| *weight > 0.0)
+                    .collect::<Vec<_>>();
+                let magnitude = vector.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+                // Paragraphs of only common words, e.g. "O God!", have no vector.
+                if magnitude == 0.0 {
+                    return vec![];
+                }
+                for (id, weight) in &mut vector {
+                    *weight /= magnitude;
+                    postings[*id].push((position, *weight));
+                }
+                vector
+            })
+            .collect();
+
+        Self { vectors, postings }
+    }
+
+    /// Up to `n` paragraphs most similar to the one with the `ref_id`, best first,
+    /// or `None` if there is no such paragraph. The same text in other compilations
+    /// (e.g. a prayer also in Prayers and Meditations) is not included.
+    ///
+    /// Other paragraphs of the same selection (prayer, Gleaning, etc., see
+    /// [`crate::EmbedAllTrait::siblings`]) are only included if `siblings` is true.
+    pub fn related(&self, ref_id: &str, n: usize, siblings: bool) -> Option<Vec<RelatedResult>> {
+        let all = Writings::all();
+        let position = *Writings::all_index().get(ref_id)?;
+        let text = all[position].text();
+        let excluded = match siblings {
+            true => vec![all[position].clone()],
+            false => all[position].siblings(),
+        };
+
+        let mut scores: HashMap<usize, f32> = HashMap::new();
+        for &(id, weight) in &self.vectors[position] {
+            for &(other, other_weight) in &self.postings[id] {
+                *scores.entry(other).or_default() += weight * other_weight;
+            }
+        }
+        let mut scores = scores
+            .into_iter()
+            .filter(|&(other, _)| {
+                other != position && !excluded.contains(&all[other]) && all[other].text() != text
+            })
+            .collect::<Vec<_>>();
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        Some(
+            scores
+                .into_iter()
+                .take(n)
+                .map(|(other, score)| RelatedResult {
+                    ref_id: all[other].ref_id(),
+                    score: score as f64,
+                    item: all[other].clone(),
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_related() {
+        let index = RelatedIndex::new(&Analyzer::default());
+        assert!(index.related("not a ref_id", 5, false).is_none());
+
+        let w = Writings::all()
+            .iter()
+            .find(|w| matches!(w, Writings::Gleaning(_)) && w.siblings().len() > 3)
+            .unwrap()
+            .clone();
+        let siblings = w.siblings();
+        let without_siblings = index.related(&w.ref_id(), 10, false).unwrap();
+        assert_eq!(without_siblings.len(), 10);
+        assert!(without_siblings.iter().all(|r| !siblings.contains(&r.item)));
+        // The same ranking, without the siblings.
+        let with_siblings = index.related(&w.ref_id(), 100, true).unwrap();
+        assert!(with_siblings.iter().any(|r| siblings.contains(&r.item)));
+        assert_eq!(
+            with_siblings
+                .into_iter()
+                .filter(|r| !siblings.contains(&r.item))
+                .take(10)
+                .collect::<Vec<_>>(),
+            without_siblings
+        );
+        assert!(
+            without_siblings
+                .iter()
+                .all(|r| r.score > 0.0 && r.score <= 1.0 + 1e-6)
+        );
+    }
+}
@@ -0,0 +1,869 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, BufWriter, Write as _},
+    ops::Range,
+    path::Path,
+    sync::OnceLock,
+};
+
+use indicium::simple::{
+    AutocompleteType, Indexable, RapidfuzzMetric, SearchIndex, SearchIndexBuilder, SearchType,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "tantivy")]
+use super::TantivyIndex;
+use super::autocomplete::Completions;
+use super::vocabulary::Vocabulary;
+use super::{
+    Analyzer, Completion, Concordance, MatchSpan, RelatedIndex, RelatedResult, ScoreExplanation,
+    SearchQuery, SearchResult, SearchTerm, SearchWeights,
+};
+use crate::embed_all::corpus_fingerprint;
+use crate::text::{fold, possessive_len, sentences, word_ranges};
+use crate::{EmbedAllTrait as _, Writings, WritingsError, WritingsResult, WritingsTrait as _};
+
+/// Ranked search over all embedded [`Writings`].
+///
+/// Candidates come from an [`indicium`] index, or from the field filters of the query;
+/// each candidate is then ranked by its best sentence, in which all but the last keyword
+/// must appear, and the last keyword must appear exactly or fuzzily (it may be incomplete
+/// while typing). See [`SearchQuery`] for the full syntax.
+/// See [`SearchWeights`] for the components of the score.
+///
+/// With the `tantivy` feature, [`SearchEngine::with_tantivy()`] instead matches whole
+/// paragraphs in a positional [`super::TantivyIndex`], ranked by BM25.
+///
+/// Building the engine indexes the whole corpus, so build it once and share it.
+pub struct SearchEngine {
+    backend: Backend,
+    weights: SearchWeights,
+    analyzer: Analyzer,
+    vocabulary: Vocabulary,
+    completions: Completions,
+    related: OnceLock<RelatedIndex>,
+    concordance: OnceLock<Concordance>,
+}
+
+/// The index that finds (and with tantivy, ranks) candidates.
+enum Backend {
+    Indicium(Box<SearchIndex<String>>),
+    #[cfg(feature = "tantivy")]
+    Tantivy(TantivyIndex),
+}
+
+impl Default for SearchEngine {
+    fn default() -> Self {
+        Self::new(SearchWeights::default())
+    }
+}
+
+impl std::fmt::Debug for SearchEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchEngine")
+            .field("weights", &self.weights)
+            .field("analyzer", &self.analyzer)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The maximum number of completions of the last word of a query to search for.
+#[cfg(feature = "tantivy")]
+const MAX_COMPLETIONS: usize = 50;
+
+impl SearchEngine {
+    /// Index all embedded Writings, ranking results with the given weights,
+    /// with the default [`Analyzer`].
+    pub fn new(weights: SearchWeights) -> Self {
+        Self::with_analyzer(weights, Analyzer::default())
+    }
+
+    /// Index all embedded Writings, ranking results with the given weights,
+    /// and reducing both indexed and query words to canonical forms with the analyzer.
+    pub fn with_analyzer(weights: SearchWeights, analyzer: Analyzer) -> Self {
+        let index = Self::indicium_index(analyzed_strings(&analyzer));
+        Self::with_backend(weights, analyzer, Backend::Indicium(Box::new(index)))
+    }
+
+    /// Load the analysis saved with [`SearchEngine::save()`], ranking results with the given
+    /// weights. Only the analysis is saved, not the [`indicium`] index, which cannot be
+    /// serialized: it is rebuilt from the analyzed words, which is faster than analyzing the
+    /// Writings again. Fails if the file was saved from different Writings (by a fingerprint
+    /// of the embedded HTML) or by a different version of this crate.
+    pub fn load(path: impl AsRef<Path>, weights: SearchWeights) -> WritingsResult<Self> {
+        let file = BufReader::new(File::open(path)?);
+        let saved: SavedIndex = serde_json::from_reader(file)?;
+        if saved.version != env!("CARGO_PKG_VERSION") {
+            return Err(WritingsError::SearchIndexOutdated(format!(
+                "saved by version {}",
+                saved.version
+            )));
+        }
+        if saved.corpus != corpus_fingerprint() {
+            return Err(WritingsError::SearchIndexOutdated(
+                "saved from different Writings".to_string(),
+            ));
+        }
+
+        let analyzer = Analyzer::with_synonym_map(saved.synonyms);
+        let index = Self::indicium_index(saved.strings);
+        Ok(Self {
+            backend: Backend::Indicium(Box::new(index)),
+            weights,
+            analyzer,
+            vocabulary: saved.vocabulary,
+            completions: saved.completions,
+            related: OnceLock::new(),
+            concordance: OnceLock::new(),
+        })
+    }
+
+    /// Save the analyzed Writings, vocabulary and titles to a file,
+    /// to [`SearchEngine::load()`] rather than analyze them again.
+    pub fn save(&self, path: impl AsRef<Path>) -> WritingsResult<()> {
+        let saved = SavedIndexRef {
+            version: env!("CARGO_PKG_VERSION"),
+            corpus: corpus_fingerprint(),
+            synonyms: self.analyzer.synonyms(),
+            strings: analyzed_strings(&self.analyzer),
+            vocabulary: &self.vocabulary,
+            completions: &self.completions,
+        };
+        let mut file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut file, &saved)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Search the tantivy index, ranking results by BM25 with the given weights,
+    /// and reducing query words to canonical forms with the analyzer, which must be
+    /// the one the index was built with.
+    #[cfg(feature = "tantivy")]
+    pub fn with_tantivy(weights: SearchWeights, analyzer: Analyzer, index: TantivyIndex) -> Self {
+        Self::with_backend(weights, analyzer, Backend::Tantivy(index))
+    }
+
+    fn with_backend(weights: SearchWeights, analyzer: Analyzer, backend: Backend) -> Self {
+        Self {
+            backend,
+            weights,
+            analyzer,
+            vocabulary: Vocabulary::new(),
+            completions: Completions::new(),
+            related: OnceLock::new(),
+            concordance: OnceLock::new(),
+        }
+    }
+
+    /// Index the analyzed strings by `ref_id`.
+    fn indicium_index(strings: Vec<(String, Vec<String>)>) -> SearchIndex<String> {
+        let mut index: SearchIndex<String> = SearchIndexBuilder::default()
+            .case_sensitive(false)
+            .autocomplete_type(AutocompleteType::Global)
+            // "thee", "thou", "thine" and "hast" are analyzed into "you", "your" and
+            // "have", which must stay searchable.
+            .exclude_keywords(None)
+            .fuzzy_length(3)
+            .max_autocomplete_options(9)
+            .max_search_results(95)
+            .rapidfuzz_metric(Some(RapidfuzzMetric::DamerauLevenshtein))
+            .fuzzy_minimum_score(0.3)
+            .build();
+
+        log::info!("Indexing Writings...");
+        for (ref_id, strings) in strings {
+            index.insert(&ref_id, &Analyzed(strings));
+        }
+        log::info!("Done indexing Writings!");
+        index
+    }
+
+    pub fn analyzer(&self) -> &Analyzer {
+        &self.analyzer
+    }
+
+    pub fn weights(&self) -> &SearchWeights {
+        &self.weights
+    }
+
+    pub fn set_weights(&mut self, weights: SearchWeights) {
+        self.weights = weights;
+    }
+
+    /// Up to `limit` completions of a partial query, best first: the query with its last
+    /// word completed, and titles and invocations (e.g. "O Son of Spirit") containing it.
+    /// Ignores case and diacritics, so "baha" completes to "Bahá’u’lláh".
+    pub fn autocomplete(&self, query: &str, limit: usize) -> Vec<Completion> {
+        self.completions.complete(&self.vocabulary, query, limit)
+    }
+
+    /// Up to `n` paragraphs most like the one with the `ref_id` ("more like this"),
+    /// or `None` if there is no such paragraph; see [`RelatedIndex::related()`].
+    /// The [`RelatedIndex`] is built on first use.
+    pub fn related(&self, ref_id: &str, n: usize, siblings: bool) -> Option<Vec<RelatedResult>> {
+        self.related
+            .get_or_init(|| RelatedIndex::new(&self.analyzer))
+            .related(ref_id, n, siblings)
+    }
+
+    /// Every occurrence of every word, with the lemmas of this engine's [`Analyzer`].
+    /// The [`Concordance`] is built on first use.
+    pub fn concordance(&self) -> &Concordance {
+        self.concordance
+            .get_or_init(|| Concordance::new(&self.analyzer))
+    }
+
+    /// Up to `limit` corrections of a query with misspelled or differently transliterated
+    /// words (e.g. "Bahaulah" or "Rezvan"), best first, from the words of all Writings.
+    /// Only corrections with results are suggested.
+    pub fn did_you_mean(&self, query: &str, limit: usize) -> Vec<String> {
+        self.vocabulary
+            .corrected_queries(query, limit)
+            .into_iter()
+            .filter(|corrected| {
+                self.search(corrected)
+                    .is_ok_and(|results| !results.is_empty())
+            })
+            .collect()
+    }
+
+    /// All results for the query, best first. See [`SearchQuery`] for the syntax.
+    pub fn search(&self, query: &str) -> WritingsResult<Vec<SearchResult>> {
+        Ok(self.search_query(&query.parse()?))
+    }
+
+    /// All results for the parsed query, best first.
+    pub fn search_query(&self, query: &SearchQuery) -> Vec<SearchResult> {
+        let filtered = !query.filters.is_empty() || !query.facets.is_empty();
+        let groups = match (query.groups.is_empty(), filtered) {
+            (true, false) => return vec![],
+            // Filters only: every matching paragraph.
+            (true, true) => vec![vec![]],
+            (false, _) => query.groups.clone(),
+        };
+
+        let filter = query.filter_query();
+        let facets = query.facet_query();
+        let all = Writings::all();
+        let all_map = Writings::all_map();
+        let mut best: HashMap<String, SearchResult> = HashMap::new();
+
+        let exclude = query
+            .exclude
+            .iter()
+            .map(|term| self.analyze_term(term))
+            .collect::<Vec<_>>();
+
+        // Infallible without the tantivy feature.
+        #[allow(clippy::infallible_destructuring_match)]
+        let index = match &self.backend {
+            Backend::Indicium(index) => index,
+            #[cfg(feature = "tantivy")]
+            Backend::Tantivy(index) => {
+                return self.search_tantivy(index, &[filter, facets], &groups, &exclude);
+            }
+        };
+
+        for original in &groups {
+            let group = &original
+                .iter()
+                .map(|term| self.analyze_term(term))
+                .collect::<Vec<_>>();
+            // With filters, rank every filtered paragraph rather than the capped index results.
+            let candidates = match (group.is_empty() || filtered, group) {
+                (true, _) => filter
+                    .positions()
+                    .into_iter()
+                    .map(|position| &all[position])
+                    .filter(|w| facets.matches(w))
+                    .collect::<Vec<_>>(),
+                (false, group) => index
+                    .search_type(&SearchType::Live, &words(group).join(" "))
+                    .into_iter()
+                    .filter_map(|ref_id| all_map.get(ref_id))
+                    .collect(),
+            };
+
+            for w in candidates {
+                let text = w.text();
+                if !exclude.is_empty() {
+                    let text_words = self.analyzed_words(&text);
+                    if exclude
+                        .iter()
+                        .any(|term| find_term(&text_words, term).is_some())
+                    {
+                        continue;
+                    }
+                }
+
+                let Some((explanation, excerpt)) = self.best_sentence(&text, group) else {
+                    continue;
+                };
+
+                let ref_id = w.ref_id();
+                let score = explanation.total();
+                if best.get(&ref_id).is_some_and(|r| r.score >= score) {
+                    continue;
+                }
+                best.insert(
+                    ref_id.clone(),
+                    SearchResult {
+                        ref_id,
+                        score,
+                        excerpt: excerpt.to_string(),
+                        excerpt_matches: self.match_spans(excerpt, original, group),
+                        matches: self.match_spans(&text, original, group),
+                        explanation: Some(explanation),
+                        ty: w.ty(),
+                        author: w.author(),
+                        item: w.clone(),
+                        collapsed: vec![],
+                    },
+                );
+            }
+        }
+
+        sorted(best.into_values().collect())
+    }
+
+    /// All paragraphs in the tantivy index matching the analyzed groups and all filters, and none
+    /// of the excluded terms, ranked by BM25. The excerpt is the best sentence for the first
+    /// group matching the paragraph; the words of a group may span several sentences.
+    #[cfg(feature = "tantivy")]
+    fn search_tantivy(
+        &self,
+        index: &TantivyIndex,
+        filters: &[crate::Query],
+        groups: &[Vec<SearchTerm>],
+        exclude: &[SearchTerm],
+    ) -> Vec<SearchResult> {
+        let analyzed = groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|term| self.analyze_term(term))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let completions = groups
+            .iter()
+            .map(|group| match group.last() {
+                Some(SearchTerm::Word(word)) => self.completions_of(word),
+                _ => vec![],
+            })
+            .collect::<Vec<_>>();
+        let hits = match index.search(&analyzed, &completions, exclude) {
+            Ok(hits) => hits,
+            Err(e) => {
+                log::error!("search index: {e}");
+                return vec![];
+            }
+        };
+
+        let all_map = Writings::all_map();
+        let results = hits
+            .into_iter()
+            .filter_map(|(ref_id, bm25)| {
+                let w = all_map.get(&ref_id)?;
+                if !filters.iter().all(|filter| filter.matches(w)) {
+                    return None;
+                }
+                let text = w.text();
+                let words = self.analyzed_words(&text);
+                let (original, group) = groups
+                    .iter()
+                    .zip(&analyzed)
+                    .find(|(_, group)| group.iter().all(|term| find_term(&words, term).is_some()))
+                    .unwrap_or((&groups[0], &analyzed[0]));
+                // Otherwise, the first sentence with the most terms.
+                let excerpt = self
+                    .best_sentence(&text, group)
+                    .map(|(_, excerpt)| excerpt)
+                    .or_else(|| {
+                        sentences(&text)
+                            .map(|s| {
+                                let words = self.analyzed_words(s);
+                                let terms = group.iter().filter(|t| find_term(&words, t).is_some());
+                                (terms.count(), s)
+                            })
+                            .reduce(|best, next| if next.0 > best.0 { next } else { best })
+                            .map(|(_, s)| s)
+                    })
+                    .unwrap_or(&text);
+                let explanation = ScoreExplanation {
+                    bm25: bm25 as f64 * self.weights.bm25,
+                    ..Default::default()
+                };
+                Some(SearchResult {
+                    ref_id,
+                    score: explanation.total(),
+                    excerpt: excerpt.to_string(),
+                    excerpt_matches: self.match_spans(excerpt, original, group),
+                    matches: self.match_spans(&text, original, group),
+                    explanation: Some(explanation),
+                    ty: w.ty(),
+                    author: w.author(),
+                    item: w.clone(),
+                    collapsed: vec![],
+                })
+            })
+            .collect();
+        sorted(results)
+    }
+
+    /// The analyzed forms of the most common words of all Writings starting with the word.
+    #[cfg(feature = "tantivy")]
+    fn completions_of(&self, word: &str) -> Vec<String> {
+        let mut words = self.vocabulary.prefixed(word).collect::<Vec<_>>();
+        words.sort_by_key(|(_, (_, count))| std::cmp::Reverse(*count));
+        let mut completions = vec![];
+        for (_, (spelling, _)) in words {
+            for word in split_into_words(spelling) {
+                let analyzed = self.analyzer.analyze(&word);
+                if !completions.contains(&analyzed) {
+                    completions.push(analyzed);
+                }
+            }
+            if completions.len() >= MAX_COMPLETIONS {
+                break;
+            }
+        }
+        completions
+    }
+
+    /// The best scoring sentence of the text for the group, and its score.
+    fn best_sentence<'a>(
+        &self,
+        text: &'a str,
+        group: &[SearchTerm],
+    ) -> Option<(ScoreExplanation, &'a str)> {
+        sentences(text)
+            .filter_map(|s| self.score_sentence(s, group).map(|score| (score, s)))
+            // The first of equally scored sentences.
+            .reduce(|best, next| {
+                if next.0.total() > best.0.total() {
+                    next
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// The words and phrases of the term, reduced to canonical forms.
+    fn analyze_term(&self, term: &SearchTerm) -> SearchTerm {
+        let analyze = |words: &[String]| words.iter().map(|w| self.analyzer.analyze(w)).collect();
+        match term {
+            SearchTerm::Word(word) => SearchTerm::Word(self.analyzer.analyze(word)),
+            SearchTerm::Phrase(words) => SearchTerm::Phrase(analyze(words)),
+            SearchTerm::Near(words, slop) => SearchTerm::Near(analyze(words), *slop),
+        }
+    }
+
+    /// The words of the text, reduced to canonical forms.
+    fn analyzed_words(&self, text: &str) -> Vec<String> {
+        split_into_words(text)
+            .iter()
+            .map(|word| self.analyzer.analyze(word))
+            .collect()
+    }
+
+    /// All matches of the (analyzed) terms within the text, in order, labelled with the
+    /// `original` terms. All but the last word must match exactly; the last word matches
+    /// fuzzily only if it does not appear exactly.
+    fn match_spans(
+        &self,
+        text: &str,
+        original: &[SearchTerm],
+        group: &[SearchTerm],
+    ) -> Vec<MatchSpan> {
+        let words = word_spans(text)
+            .into_iter()
+            .map(|(range, word)| (range, self.analyzer.analyze(&word)))
+            .collect::<Vec<_>>();
+        let mut spans = vec![];
+
+        for (i, (term, original)) in group.iter().zip(original).enumerate() {
+            let label = original.words().join(" ");
+            match term {
+                SearchTerm::Word(keyword) => {
+                    let span = |(range, _): &(Range<usize>, String), exact| MatchSpan {
+                        start: range.start,
+                        end: range.end,
+                        term: label.clone(),
+                        exact,
+                    };
+                    let exact = words
+                        .iter()
+                        .filter(|(_, word)| word.contains(keyword.as_str()))
+                        .map(|w| span(w, true))
+                        .collect::<Vec<_>>();
+                    if exact.is_empty() && i == group.len() - 1 {
+                        spans.extend(
+                            words
+                                .iter()
+                                .filter(|(_, word)| {
+                                    similarity(keyword, word) >= self.weights.min_fuzzy_similarity
+                                })
+                                .map(|w| span(w, false)),
+                        );
+                    } else {
+                        spans.extend(exact);
+                    }
+                }
+                SearchTerm::Phrase(phrase) | SearchTerm::Near(phrase, _) => {
+                    let analyzed = words.iter().map(|(_, w)| w).collect::<Vec<_>>();
+                    spans.extend((0..words.len()).filter_map(|start| {
+                        let end = phrase_end(&analyzed, start, phrase, term.slop())?;
+                        Some(MatchSpan {
+                            start: words[start].0.start,
+                            end: words[end - 1].0.end,
+                            term: label.clone(),
+                            exact: true,
+                        })
+                    }));
+                }
+            }
+        }
+
+        spans.sort_by_key(|span| (span.start, span.end));
+        spans
+    }
+
+    /// Score a sentence, or `None` if it does not match all terms.
+    /// Every sentence matches an empty group, with a score of zero.
+    fn score_sentence(&self, sentence: &str, group: &[SearchTerm]) -> Option<ScoreExplanation> {
+        let Some((last, terms)) = group.split_last() else {
+            return Some(ScoreExplanation::default());
+        };
+        let weights = &self.weights;
+        let words = self.analyzed_words(sentence);
+
+        // 1. Term positions and presence checks
+        let mut positions = Vec::with_capacity(group.len());
+        for term in terms {
+            positions.push(find_term(&words, term)?);
+        }
+
+        // 2. Last keyword handling with minimum fuzzy threshold; phrases must match exactly
+        let (last_present, last_pos, fuzzy_score) = match (find_term(&words, last), last) {
+            (Some(pos), _) => (true, pos, 1.0),
+            (None, SearchTerm::Phrase(_) | SearchTerm::Near(..)) => return None,
+            (None, SearchTerm::Word(last_keyword)) => {
+                let (best_score, best_pos) = words
+                    .iter()
+                    .enumerate()
+                    .map(|(pos, word)| (similarity(last_keyword, word), pos))
+                    .max_by(|a, b| a.0.total_cmp(&b.0))
+                    .unwrap_or((0.0, 0));
+
+                if best_score < weights.min_fuzzy_similarity {
+                    return None;
+                }
+                (false, best_pos, best_score)
+            }
+        };
+        positions.push(last_pos);
+
+        // 3. Scoring components
+        // a. Order score (all keywords in sequence)
+        let order_score = positions
+            .windows(2)
+            .filter(|pair| pair[1] >= pair[0])
+            .count();
+
+        // b. Proximity score (average distance between consecutive keywords)
+        let proximity_score: f64 = positions
+            .windows(2)
+            .map(|pair| 1.0 / (pair[1].abs_diff(pair[0]) as f64 + 1.0))
+            .sum::<f64>()
+            / (positions.len() - 1).max(1) as f64;
+
+        // c. Position bonus (earlier first keyword better)
+        let position_score = 1.0 / (positions[0] as f64 + 1.0);
+
+        // d. Exact match bonus for last keyword
+        let exact_last_bonus = last_present as usize;
+
+        Some(ScoreExplanation {
+            order: order_score as f64 * weights.order,
+            proximity: proximity_score * weights.proximity,
+            position: position_score * weights.position,
+            exact_last: exact_last_bonus as f64 * weights.exact_last,
+            fuzzy: fuzzy_score * weights.fuzzy,
+            bm25: 0.0,
+        })
+    }
+}
+
+/// The results by score, best first, with ties in document order, so results are stable.
+fn sorted(mut results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let index = Writings::all_index();
+    results.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| index.get(&a.ref_id).cmp(&index.get(&b.ref_id)))
+    });
+    results
+}
+
+/// All words of the terms, in order.
+fn words(terms: &[SearchTerm]) -> Vec<&str> {
+    terms
+        .iter()
+        .flat_map(SearchTerm::words)
+        .map(String::as_str)
+        .collect()
+}
+
+/// The position of the first word matching the term: a word containing the keyword,
+/// or the start of the phrase.
+fn find_term(words: &[String], term: &SearchTerm) -> Option<usize> {
+    match term {
+        SearchTerm::Word(keyword) => words.iter().position(|word| word.contains(keyword)),
+        SearchTerm::Phrase(phrase) | SearchTerm::Near(phrase, _) => {
+            (0..words.len()).find(|&start| phrase_end(words, start, phrase, term.slop()).is_some())
+        }
+    }
+}
+
+/// The end (exclusive) of the phrase starting at `start` in the words, with at most `slop`
+/// other words between the words of the phrase in total, or `None` if it does not start there.
+fn phrase_end<W: AsRef<str>>(
+    words: &[W],
+    start: usize,
+    phrase: &[String],
+    slop: u32,
+) -> Option<usize> {
+    let (first, rest) = phrase.split_first()?;
+    if words.get(start)?.as_ref() != first {
+        return None;
+    }
+    let mut end = start + 1;
+    let mut gaps = 0;
+    for word in rest {
+        let remaining = slop as usize - gaps;
+        let skip = words
+            .get(end..)?
+            .iter()
+            .take(remaining + 1)
+            .position(|w| w.as_ref() == word)?;
+        gaps += skip;
+        end += skip + 1;
+    }
+    Some(end)
+}
+
+/// The `ref_id` and analyzed strings of all Writings, in document order.
+fn analyzed_strings(analyzer: &Analyzer) -> Vec<(String, Vec<String>)> {
+    Writings::all()
+        .iter()
+        .map(|w| {
+            let strings = w
+                .strings()
+                .iter()
+                .map(|s| analyzer.analyze_text(s))
+                .collect();
+            (w.ref_id(), strings)
+        })
+        .collect()
+}
+
+/// The contents of a file written by [`SearchEngine::save()`].
+#[derive(Deserialize)]
+struct SavedIndex {
+    version: String,
+    /// The [`corpus_fingerprint()`] of the Writings analyzed.
+    corpus: u64,
+    synonyms: HashMap<String, String>,
+    strings: Vec<(String, Vec<String>)>,
+    vocabulary: Vocabulary,
+    completions: Completions,
+}
+
+/// [`SavedIndex`] by reference, for saving.
+#[derive(Serialize)]
+struct SavedIndexRef<'a> {
+    version: &'a str,
+    corpus: u64,
+    synonyms: &'a HashMap<String, String>,
+    strings: Vec<(String, Vec<String>)>,
+    vocabulary: &'a Vocabulary,
+    completions: &'a Completions,
+}
+
+/// Analyzed strings to index in place of those of the [`Writings`].
+struct Analyzed(Vec<String>);
+
+impl Indexable for Analyzed {
+    fn strings(&self) -> Vec<String> {
+        self.0.clone()
+    }
+}
+
+/// Similarity (0.0 to 1.0) of two words.
+fn similarity(a: &str, b: &str) -> f64 {
+    rapidfuzz::distance::lcs_seq::similarity(a.chars(), b.chars()) as f64
+        / a.len().max(b.len()) as f64
+}
+
+/// Split into lowercase words without diacritics.
+pub(super) fn split_into_words(input: &str) -> Vec<String> {
+    word_spans(input)
+        .into_iter()
+        .map(|(_, word)| word)
+        .collect()
+}
+
+/// Each word of the input with its `char` range, folded (see [`search_word_ranges()`]).
+pub(super) fn word_spans(input: &str) -> Vec<(Range<usize>, String)> {
+    let mut chars = 0;
+    let mut bytes = 0;
+    search_word_ranges(input)
+        .into_iter()
+        .map(|range| {
+            chars += input[bytes..range.start].chars().count();
+            let start = chars;
+            chars += input[range.clone()].chars().count();
+            bytes = range.end;
+            (start..chars, fold(&input[range]))
+        })
+        .filter(|(_, word)| !word.is_empty())
+        .collect()
+}
+
+/// The byte ranges of the words of the text to search, folded with [`fold()`] in lowercase
+/// ASCII. These are the words of [`word_ranges()`], as in statistics and the glossary, so
+/// "Bahá’u’lláh" is one word, but without a possessive "’s", so "God" finds "God’s", and
+/// split at hyphens, so "Aqdas" finds "Kitáb-i-Aqdas" and "being" finds "well-being".
+pub(super) fn search_word_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    for range in word_ranges(text) {
+        let end = range.end - possessive_len(&text[range.clone()]);
+        let mut start = range.start;
+        for (i, c) in text[range.start..end].char_indices() {
+            if matches!(c, '-' | '‑') {
+                ranges.push(start..range.start + i);
+                start = range.start + i + c.len_utf8();
+            }
+        }
+        ranges.push(start..end);
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_spans() {
+        let spans = word_spans("O Bahá’u’lláh! God’s Kitáb-i-Aqdas");
+        assert_eq!(
+            spans,
+            vec![
+                (0..1, "o".to_string()),
+                (2..13, "bahaullah".to_string()),
+                (15..18, "god".to_string()),
+                (21..26, "kitab".to_string()),
+                (27..28, "i".to_string()),
+                (29..34, "aqdas".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phrase_end() {
+        let words = ["the", "ocean", "of", "my", "presence"];
+        let phrase = ["ocean".to_string(), "presence".to_string()];
+        assert_eq!(phrase_end(&words, 1, &phrase, 2), Some(5));
+        assert_eq!(phrase_end(&words, 1, &phrase, 1), None);
+        assert_eq!(phrase_end(&words, 0, &phrase, 2), None);
+        assert_eq!(phrase_end(&words, 1, &phrase[..1], 0), Some(2));
+    }
+
+    #[test]
+    fn test_facets() {
+        use crate::search::SearchFilter;
+        use crate::{Author, WritingsType};
+
+        let engine = SearchEngine::default();
+        let search = |q: &str, facets: Vec<SearchFilter>| {
+            let mut query: SearchQuery = q.parse().unwrap();
+            query.facets = facets;
+            engine.search_query(&query)
+        };
+
+        // Facets narrow the filters of the query rather than adding alternatives to them.
+        let gleanings = vec![SearchFilter::Type(WritingsType::Gleaning)];
+        assert!(search("mercy type:prayer", gleanings.clone()).is_empty());
+        let results = search("mercy type:prayer type:gleaning", gleanings.clone());
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| r.ty == WritingsType::Gleaning));
+
+        // Facets alone are uncapped candidates, like filters.
+        assert_eq!(
+            search("", gleanings.clone()).len(),
+            search("type:gleaning", vec![]).len()
+        );
+        let results = search("mercy", vec![SearchFilter::Author(Author::TheBab)]);
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| r.author == Author::TheBab));
+    }
+
+    #[test]
+    fn test_modern_and_archaic_words() {
+        let engine = SearchEngine::default();
+        for query in ["you", "your", "have", "thee", "thine", "hast"] {
+            assert!(!engine.search(query).unwrap().is_empty(), "{query}");
+        }
+    }
+
+    #[test]
+    fn test_save_load() {
+        let engine = SearchEngine::default();
+        let path = std::env::temp_dir().join(format!(
+            "writings-test-search-index-{}.json",
+            std::process::id()
+        ));
+        engine.save(&path).unwrap();
+        let loaded = SearchEngine::load(&path, SearchWeights::default()).unwrap();
+
+        // A file saved from other Writings is out of date.
+        let mut saved: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        saved["corpus"] = serde_json::json!(corpus_fingerprint() ^ 1);
+        std::fs::write(&path, saved.to_string()).unwrap();
+        assert!(matches!(
+            SearchEngine::load(&path, SearchWeights::default()),
+            Err(WritingsError::SearchIndexOutdated(_))
+        ));
+
+        let ref_ids = |engine: &SearchEngine, query| {
+            engine
+                .search(query)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.ref_id)
+                .collect::<Vec<_>>()
+        };
+        for query in [
+            "believeth",
+            "\"ocean of my presence\"",
+            "mercy OR justice -fire",
+        ] {
+            assert_eq!(ref_ids(&engine, query), ref_ids(&loaded, query), "{query}");
+        }
+        assert_eq!(
+            engine.autocomplete("son of sp", 3),
+            loaded.autocomplete("son of sp", 3)
+        );
+        assert_eq!(
+            engine.did_you_mean("Rezvan", 3),
+            loaded.did_you_mean("Rezvan", 3)
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+use std::{collections::HashMap, hash::Hash};
+
+use serde::{Deserialize, Serialize};
+
+use super::SearchResult;
+use crate::{Author, PrayerKind, Work, Writings, WritingsTrait as _, WritingsType};
+
+macro_rules! facet_count {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("The number of hits with a [`", stringify!($ty), "`].")]
+        #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        #[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+        #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+        pub struct $name {
+            pub value: $ty,
+            pub count: usize,
+        }
+
+        impl From<($ty, usize)> for $name {
+            fn from((value, count): ($ty, usize)) -> Self {
+                Self { value, count }
+            }
+        }
+    };
+}
+
+facet_count!(TypeCount, WritingsType);
+facet_count!(AuthorCount, Author);
+facet_count!(PrayerKindCount, PrayerKind);
+facet_count!(WorkCount, Work);
+
+/// Counts of search hits (paragraphs) by type, author, prayer kind and Work,
+/// each sorted by descending count.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct SearchFacets {
+    pub types: Vec<TypeCount>,
+    pub authors: Vec<AuthorCount>,
+    /// Only prayers are counted.
+    pub prayer_kinds: Vec<PrayerKindCount>,
+    pub works: Vec<WorkCount>,
+}
+
+impl SearchFacets {
+    /// Count the results, including any collapsed into them.
+    pub fn new(results: &[SearchResult]) -> Self {
+        Self::count(
+            results
+                .iter()
+                .flat_map(|r| std::iter::repeat_n(&r.item, 1 + r.collapsed.len())),
+        )
+    }
+
+    /// Count any hits, e.g. the paragraphs of each [`super::Occurrence`] of a word.
+    pub fn count<'a>(items: impl Iterator<Item = &'a Writings> + Clone) -> Self {
+        let hits = || items.clone();
+        Self {
+            types: count(hits().map(|w| w.ty())),
+            authors: count(hits().map(|w| w.author())),
+            prayer_kinds: count(hits().filter_map(|w| match w {
+                Writings::Prayer(p) => Some(p.kind),
+                _ => None,
+            })),
+            works: count(hits().map(|w| w.work())),
+        }
+    }
+}
+
+/// Count each value, in descending order of count, then first appearance.
+fn count<T: Copy + Eq + Hash, C: From<(T, usize)>>(values: impl Iterator<Item = T>) -> Vec<C> {
+    let mut counts: Vec<(T, usize)> = vec![];
+    let mut index: HashMap<T, usize> = HashMap::new();
+    for value in values {
+        match index.get(&value) {
+            Some(&i) => counts[i].1 += 1,
+            None => {
+                index.insert(value, counts.len());
+                counts.push((value, 1));
+            }
+        }
+    }
+    counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    counts.into_iter().map(C::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        let counts: Vec<AuthorCount> =
+            count([Author::TheBab, Author::Bahaullah, Author::Bahaullah].into_iter());
+        assert_eq!(
+            counts,
+            vec![
+                AuthorCount::from((Author::Bahaullah, 2)),
+                AuthorCount::from((Author::TheBab, 1)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// A matched term within a text, by `char` offsets.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct MatchSpan {
+    /// The offset of the first `char` of the match.
+    pub start: usize,
+
+    /// The offset after the last `char` of the match.
+    pub end: usize,
+
+    /// The query term (lowercase, without diacritics) that matched.
+    pub term: String,
+
+    /// Whether the term matched exactly, or only fuzzily.
+    pub exact: bool,
+}
+
+/// The weighted components of the score of a [`super::SearchResult`];
+/// see [`super::SearchWeights`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ScoreExplanation {
+    pub order: f64,
+    pub proximity: f64,
+    pub position: f64,
+    pub exact_last: f64,
+    pub fuzzy: f64,
+    /// Only with a [`super::TantivyIndex`], which ranks by BM25 alone.
+    #[serde(default)]
+    pub bm25: f64,
+}
+
+impl ScoreExplanation {
+    /// The total score, rounded.
+    pub fn total(&self) -> f64 {
+        (self.order + self.proximity + self.position + self.exact_last + self.fuzzy + self.bm25)
+            .round()
+    }
+}
@@ -0,0 +1,366 @@
+use std::str::FromStr;
+
+use strum::IntoEnumIterator as _;
+
+use super::search_engine::split_into_words;
+use crate::text::fold;
+use crate::{Author, HiddenWordKind, PrayerKind, Work, WritingsError, WritingsType};
+
+/// A parsed search query.
+///
+/// # Syntax
+///
+/// - `mercy justice`: both words must appear in the same sentence; the last word may be
+///   incomplete or misspelled.
+/// - `"tabernacle of unity"`: the exact phrase must appear in the sentence.
+/// - `"ocean presence"~3`: the words must appear in order, with at most 3 other words
+///   between them in total.
+/// - `-fire`, `-"land of knowledge"`: the paragraph must not contain the word or phrase.
+/// - `love OR mercy justice`: either side matches; `OR` separates whole alternatives,
+///   i.e. this is `love` or (`mercy` and `justice`).
+/// - `author:abdulbaha`, `type:prayer`, `kind:obligatory`, `work:gleanings`: field filters,
+///   which apply to the whole query; repeating a field matches any of its values. Other
+///   words followed by a colon, e.g. `Say:`, are searched as words.
+///
+/// ```
+/// use writings::search::{SearchQuery, SearchTerm};
+///
+/// let query: SearchQuery = r#""ocean of My presence" -fire type:gleaning"#.parse().unwrap();
+/// assert_eq!(query.groups, vec![vec![SearchTerm::Phrase(vec![
+///     "ocean".to_string(), "of".to_string(), "my".to_string(), "presence".to_string(),
+/// ])]]);
+/// assert_eq!(query.exclude, vec![SearchTerm::Word("fire".to_string())]);
+///
+/// assert!("\"unterminated".parse::<SearchQuery>().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    /// Alternatives separated by `OR`; all terms of any one group must match.
+    pub groups: Vec<Vec<SearchTerm>>,
+
+    /// Terms that must not appear anywhere in the paragraph.
+    pub exclude: Vec<SearchTerm>,
+
+    /// Field filters that apply to all groups.
+    pub filters: Vec<SearchFilter>,
+
+    /// Field filters that must match as well as `filters`, e.g. the facets selected
+    /// alongside a query: `type:prayer` in `filters` and [`SearchFilter::Type`] of
+    /// Gleanings here match nothing, rather than either type. Not set by parsing.
+    pub facets: Vec<SearchFilter>,
+}
+
+/// A word or phrase in a [`SearchQuery`], lowercase without diacritics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchTerm {
+    Word(String),
+    Phrase(Vec<String>),
+    /// Words in order, with at most this many other words between them in total.
+    Near(Vec<String>, u32),
+}
+
+impl SearchTerm {
+    /// The words of the term, in order.
+    pub fn words(&self) -> &[String] {
+        match self {
+            SearchTerm::Word(word) => std::slice::from_ref(word),
+            SearchTerm::Phrase(words) | SearchTerm::Near(words, _) => words,
+        }
+    }
+
+    /// The number of other words allowed between the words of the term.
+    pub fn slop(&self) -> u32 {
+        match self {
+            SearchTerm::Near(_, slop) => *slop,
+            _ => 0,
+        }
+    }
+}
+
+/// A `field:value` filter in a [`SearchQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchFilter {
+    Type(WritingsType),
+    Author(Author),
+    PrayerKind(PrayerKind),
+    HiddenWordKind(HiddenWordKind),
+    Work(Work),
+}
+
+impl SearchQuery {
+    /// Whether the query has no terms or filters, and so matches nothing.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty() && self.filters.is_empty() && self.facets.is_empty()
+    }
+
+    /// The field filters as a [`crate::Query`].
+    pub fn filter_query(&self) -> crate::Query {
+        to_query(&self.filters)
+    }
+
+    /// The facet filters as a [`crate::Query`], which must match as well as
+    /// [`SearchQuery::filter_query()`].
+    pub fn facet_query(&self) -> crate::Query {
+        to_query(&self.facets)
+    }
+}
+
+fn to_query(filters: &[SearchFilter]) -> crate::Query {
+    filters
+        .iter()
+        .fold(crate::Query::new(), |query, filter| match *filter {
+            SearchFilter::Type(ty) => query.ty(ty),
+            SearchFilter::Author(author) => query.author(author),
+            SearchFilter::PrayerKind(kind) => query.prayer_kind(kind),
+            SearchFilter::HiddenWordKind(kind) => query.hidden_word_kind(kind),
+            SearchFilter::Work(work) => query.work(work),
+        })
+}
+
+impl FromStr for SearchQuery {
+    type Err = WritingsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s.chars().collect::<Vec<_>>();
+        let mut query = SearchQuery::default();
+        let mut group = vec![];
+        // The position of the last `OR`, while no term has followed it yet.
+        let mut pending_or = None;
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i].is_whitespace() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+
+            let negate = chars[i] == '-';
+            if negate {
+                i += 1;
+                if i == chars.len() || chars[i].is_whitespace() {
+                    return Err(error(start, "expected a word or phrase after `-`"));
+                }
+            }
+
+            let term = if is_quote(chars[i]) {
+                let Some(len) = chars[i + 1..].iter().position(|&c| is_quote(c)) else {
+                    return Err(error(i, "unterminated phrase"));
+                };
+                let phrase = chars[i + 1..i + 1 + len].iter().collect::<String>();
+                i += len + 2;
+                let words = split_into_words(&phrase);
+                if words.is_empty() {
+                    return Err(error(start, "empty phrase"));
+                }
+                match chars.get(i) {
+                    Some('~') => {
+                        let len = chars[i + 1..]
+                            .iter()
+                            .position(|c| !c.is_ascii_digit())
+                            .unwrap_or(chars.len() - i - 1);
+                        let slop = chars[i + 1..i + 1 + len].iter().collect::<String>();
+                        let Ok(slop) = slop.parse() else {
+                            return Err(error(i, "expected a number of words after `~`"));
+                        };
+                        i += len + 1;
+                        SearchTerm::Near(words, slop)
+                    }
+                    _ => SearchTerm::Phrase(words),
+                }
+            } else {
+                let len = chars[i..]
+                    .iter()
+                    .position(|c| c.is_whitespace())
+                    .unwrap_or(chars.len() - i);
+                let token = chars[i..i + len].iter().collect::<String>();
+                i += len;
+
+                if token == "OR" && !negate {
+                    if group.is_empty() || pending_or.is_some() {
+                        return Err(error(start, "`OR` must be between terms"));
+                    }
+                    query.groups.push(std::mem::take(&mut group));
+                    pending_or = Some(start);
+                    continue;
+                }
+
+                if let Some((field, value)) = token.split_once(':')
+                    && FIELDS.contains(&field.to_lowercase().as_str())
+                {
+                    if negate {
+                        return Err(error(start, "field filters cannot be excluded"));
+                    }
+                    query.filters.push(parse_filter(field, value, start)?);
+                    continue;
+                }
+
+                let mut words = split_into_words(&token);
+                match words.len() {
+                    0 => continue,
+                    1 => SearchTerm::Word(words.remove(0)),
+                    // e.g. Kitáb-i-Aqdas: its parts must stay together.
+                    _ if negate => SearchTerm::Phrase(words),
+                    _ => {
+                        group.extend(words.into_iter().map(SearchTerm::Word));
+                        pending_or = None;
+                        continue;
+                    }
+                }
+            };
+
+            if negate {
+                query.exclude.push(term);
+            } else {
+                group.push(term);
+                pending_or = None;
+            }
+        }
+
+        if let Some(position) = pending_or {
+            return Err(error(position, "`OR` must be between terms"));
+        }
+        if !group.is_empty() {
+            query.groups.push(group);
+        }
+
+        Ok(query)
+    }
+}
+
+/// The fields of `field:value` filters.
+const FIELDS: &[&str] = &["type", "author", "kind", "work"];
+
+fn is_quote(c: char) -> bool {
+    matches!(c, '"' | '“' | '”')
+}
+
+fn error(position: usize, message: &str) -> WritingsError {
+    WritingsError::SearchQuery {
+        position,
+        message: message.to_string(),
+    }
+}
+
+fn parse_filter(field: &str, value: &str, position: usize) -> Result<SearchFilter, WritingsError> {
+    let folded = fold(value);
+    let is = |debug: String, display: String| {
+        let display = fold(&display);
+        fold(&debug) == folded
+            || display == folded
+            || display.strip_prefix("the") == Some(folded.as_str())
+    };
+
+    let filter = match field.to_lowercase().as_str() {
+        "type" => WritingsType::iter()
+            .find(|ty| is(format!("{ty:?}"), format!("{ty:?}")))
+            .map(SearchFilter::Type),
+        "author" => Author::iter()
+            .find(|author| is(format!("{author:?}"), author.to_string()))
+            .map(SearchFilter::Author),
+        "kind" => PrayerKind::iter()
+            .find(|kind| is(format!("{kind:?}"), kind.to_string()))
+            .map(SearchFilter::PrayerKind)
+            .or_else(|| {
+                [HiddenWordKind::Arabic, HiddenWordKind::Persian]
+                    .into_iter()
+                    .find(|kind| is(format!("{kind:?}"), kind.to_string()))
+                    .map(SearchFilter::HiddenWordKind)
+            }),
+        "work" => Work::from_abbreviation(value)
+            .or_else(|| Work::iter().find(|work| is(format!("{work:?}"), work.title())))
+            .map(SearchFilter::Work),
+        _ => return Err(error(position, &format!("unknown field `{field}`"))),
+    };
+
+    filter.ok_or_else(|| error(position, &format!("unknown {field} `{value}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(s: &str) -> SearchTerm {
+        SearchTerm::Word(s.to_string())
+    }
+
+    #[test]
+    fn test_parse() {
+        let query: SearchQuery = "love OR mercy justice -fire author:bab kind:persian"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            query.groups,
+            vec![vec![word("love")], vec![word("mercy"), word("justice")]]
+        );
+        assert_eq!(query.exclude, vec![word("fire")]);
+        assert_eq!(
+            query.filters,
+            vec![
+                SearchFilter::Author(Author::TheBab),
+                SearchFilter::HiddenWordKind(HiddenWordKind::Persian),
+            ]
+        );
+
+        let query: SearchQuery = "“Kitáb-i-Aqdas” -Kitáb-i-Aqdas work:hw".parse().unwrap();
+        let aqdas = SearchTerm::Phrase(["kitab", "i", "aqdas"].map(String::from).to_vec());
+        assert_eq!(query.groups, vec![vec![aqdas.clone()]]);
+        assert_eq!(query.exclude, vec![aqdas]);
+        assert_eq!(query.filters, vec![SearchFilter::Work(Work::HiddenWords)]);
+
+        let query: SearchQuery = "Bahá’u’lláh’s -Baha'u'llah".parse().unwrap();
+        assert_eq!(query.groups, vec![vec![word("bahaullah")]]);
+        assert_eq!(query.exclude, vec![word("bahaullah")]);
+
+        // Other words followed by a colon are words, e.g. in quotations.
+        let query: SearchQuery = "Say: O people color:red".parse().unwrap();
+        assert_eq!(
+            query.groups,
+            vec![["say", "o", "people", "color", "red"].map(word).to_vec()]
+        );
+        assert!(query.filters.is_empty());
+        let query: SearchQuery = "“My first counsel is this: Possess a pure heart” Type:Prayer"
+            .parse()
+            .unwrap();
+        let words = "my first counsel is this possess a pure heart".split(' ');
+        assert_eq!(
+            query.groups,
+            vec![vec![SearchTerm::Phrase(words.map(String::from).collect())]]
+        );
+        assert_eq!(
+            query.filters,
+            vec![SearchFilter::Type(WritingsType::Prayer)]
+        );
+
+        let query: SearchQuery = r#""ocean presence"~3 love"#.parse().unwrap();
+        assert_eq!(
+            query.groups,
+            vec![vec![
+                SearchTerm::Near(vec!["ocean".to_string(), "presence".to_string()], 3),
+                word("love")
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (query, position) in [
+            ("OR love", 0),
+            ("love OR", 5),
+            ("love OR OR mercy", 8),
+            ("love \"mercy", 5),
+            ("\"\"", 0),
+            ("\"love mercy\"~ justice", 12),
+            ("love - mercy", 5),
+            ("author:nobody", 0),
+            ("-type:prayer", 0),
+        ] {
+            match query.parse::<SearchQuery>() {
+                Err(WritingsError::SearchQuery { position: p, .. }) => {
+                    assert_eq!(p, position, "{query}")
+                }
+                other => panic!("{query}: {other:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{MatchSpan, ScoreExplanation};
+use crate::{Author, Writings, WritingsTrait as _, WritingsType};
+
+/// A single ranked hit from a [`super::SearchEngine`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct SearchResult {
+    /// The reference ID of the matching paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    /// The relevance of the hit; higher is better. Only comparable within the same query.
+    pub score: f64,
+
+    /// The best matching sentence of the paragraph.
+    pub excerpt: String,
+
+    /// The matched terms within the `excerpt`.
+    pub excerpt_matches: Vec<MatchSpan>,
+
+    /// The matched terms within the full `text` of the `item`.
+    pub matches: Vec<MatchSpan>,
+
+    /// The components of the `score`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<ScoreExplanation>,
+
+    pub ty: WritingsType,
+
+    pub author: Author,
+
+    /// The full matching paragraph.
+    pub item: Writings,
+
+    /// The `ref_id`s of other matching paragraphs of the same selection (prayer, Gleaning, etc.),
+    /// if collapsed into this one by [`SearchResult::collapse`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collapsed: Vec<String>,
+}
+
+impl SearchResult {
+    /// Collapse results from the same selection (e.g. paragraphs of the same prayer or Gleaning,
+    /// as in [`crate::EmbedAllTrait::siblings`]) into the best of them, keeping the order.
+    pub fn collapse(results: Vec<SearchResult>) -> Vec<SearchResult> {
+        let mut collapsed: Vec<SearchResult> = Vec::with_capacity(results.len());
+        let mut selections = HashMap::new();
+        for result in results {
+            let w = &result.item;
+            let key = (w.ty(), w.title(), w.subtitle(), w.number());
+            match selections.get(&key) {
+                Some(&i) => {
+                    let best: &mut SearchResult = &mut collapsed[i];
+                    best.collapsed.push(result.ref_id);
+                }
+                None => {
+                    selections.insert(key, collapsed.len());
+                    collapsed.push(result);
+                }
+            }
+        }
+        collapsed
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// The weights of each component of the score of a [`super::SearchResult`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct SearchWeights {
+    /// Per pair of consecutive keywords appearing in the order of the query.
+    pub order: f64,
+
+    /// Inverse of the average distance (in words) between consecutive keywords.
+    pub proximity: f64,
+
+    /// Inverse of the position (in words) of the first keyword in the sentence.
+    pub position: f64,
+
+    /// If the last keyword (which may be incomplete) appears in the sentence exactly.
+    pub exact_last: f64,
+
+    /// Similarity (0.0 to 1.0) of the last keyword to the closest word in the sentence.
+    pub fuzzy: f64,
+
+    /// Per unit of BM25 score of the paragraph, only with a [`super::TantivyIndex`],
+    /// which ranks by BM25 alone.
+    pub bm25: f64,
+
+    /// Minimum similarity (0.0 to 1.0) for the last keyword to match a word fuzzily.
+    pub min_fuzzy_similarity: f64,
+}
+
+impl Default for SearchWeights {
+    fn default() -> Self {
+        Self {
+            order: 800.0,
+            proximity: 600.0,
+            position: 400.0,
+            exact_last: 1000.0,
+            fuzzy: 500.0,
+            bm25: 100.0,
+            min_fuzzy_similarity: 0.7,
+        }
+    }
+}
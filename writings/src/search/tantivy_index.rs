@@ -0,0 +1,340 @@
+#![cfg(feature = "tantivy")]
+
+use std::path::Path;
+
+use indicium::simple::Indexable as _;
+use serde::{Deserialize, Serialize};
+use tantivy::{
+    DocAddress, Index, IndexReader, IndexSettings, TantivyDocument, TantivyError, Term,
+    collector::TopDocs,
+    directory::MmapDirectory,
+    query::{
+        AllQuery, BooleanQuery, FuzzyTermQuery, Occur, PhraseQuery, Query as TantivyQuery,
+        TermQuery,
+    },
+    schema::{
+        Field, IndexRecordOption, STORED, STRING, Schema, TextFieldIndexing, TextOptions,
+        Value as _,
+    },
+    tokenizer::{PreTokenizedString, Token},
+};
+
+use super::{Analyzer, SearchTerm, search_engine::search_word_ranges};
+use crate::embed_all::corpus_fingerprint;
+use crate::{EmbedAllTrait as _, Writings, WritingsResult, WritingsTrait as _};
+
+/// The memory budget of the index writer while building the index.
+const WRITER_MEMORY: usize = 50_000_000;
+
+/// A [`tantivy`] index of all embedded Writings, for [`super::SearchEngine::with_tantivy()`].
+///
+/// Words are indexed with their positions, after the same [`Analyzer`] as queries,
+/// for exact phrase and proximity (`"…"~n`) queries ranked by BM25.
+///
+/// The index can be kept on disk and memory-mapped, rather than rebuilt on every start.
+/// It is rebuilt when the crate version, the embedded Writings, the [`Analyzer`] or the
+/// schema change.
+pub struct TantivyIndex {
+    reader: IndexReader,
+    ref_id: Field,
+    text: Field,
+}
+
+impl std::fmt::Debug for TantivyIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TantivyIndex")
+            .field("num_docs", &self.reader.searcher().num_docs())
+            .finish_non_exhaustive()
+    }
+}
+
+impl TantivyIndex {
+    /// Index all embedded Writings in memory.
+    pub fn in_memory(analyzer: &Analyzer) -> WritingsResult<Self> {
+        Self::build(Index::create_in_ram(schema()), analyzer)
+    }
+
+    /// Open the index in the directory, building it first if it is missing or out of date.
+    pub fn open_or_build(directory: impl AsRef<Path>, analyzer: &Analyzer) -> WritingsResult<Self> {
+        std::fs::create_dir_all(&directory)?;
+        let directory = MmapDirectory::open(directory)?;
+        let index = match Index::open_or_create(directory.clone(), schema()) {
+            Ok(index) => Some(index),
+            Err(TantivyError::SchemaError(err)) => {
+                log::info!("Search index is out of date: {err}");
+                None
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if let Some(index) = index
+            && BuiltFrom::of(&index)? == Some(BuiltFrom::new(analyzer))
+        {
+            let num_docs = index.reader()?.searcher().num_docs();
+            log::info!("Opened search index of {num_docs} Writings");
+            return Self::with_index(index);
+        }
+        Self::build(
+            Index::create(directory, schema(), IndexSettings::default())?,
+            analyzer,
+        )
+    }
+
+    fn with_index(index: Index) -> WritingsResult<Self> {
+        let schema = index.schema();
+        Ok(Self {
+            reader: index.reader()?,
+            ref_id: schema.get_field("ref_id")?,
+            text: schema.get_field("text")?,
+        })
+    }
+
+    /// Replace any documents in the index with all embedded Writings.
+    fn build(index: Index, analyzer: &Analyzer) -> WritingsResult<Self> {
+        log::info!("Building search index...");
+        let this = Self::with_index(index.clone())?;
+        let mut writer = index.writer(WRITER_MEMORY)?;
+        writer.delete_all_documents()?;
+        for w in Writings::all().iter() {
+            let mut doc = TantivyDocument::new();
+            doc.add_text(this.ref_id, w.ref_id());
+            for string in w.strings() {
+                doc.add_pre_tokenized_text(this.text, tokenize(&string, analyzer));
+            }
+            writer.add_document(doc)?;
+        }
+        let mut commit = writer.prepare_commit()?;
+        commit.set_payload(&serde_json::to_string(&BuiltFrom::new(analyzer))?);
+        commit.commit()?;
+        this.reader.reload()?;
+        log::info!("Done building search index!");
+        Ok(this)
+    }
+
+    /// The `ref_id`s and BM25 scores of all paragraphs matching any of the (analyzed) groups
+    /// of terms, and none of the excluded terms, best first. An empty group matches every
+    /// paragraph.
+    ///
+    /// The last word of each group may be misspelled, or incomplete: it also matches the
+    /// (analyzed) `completions` of that group.
+    pub(super) fn search(
+        &self,
+        groups: &[Vec<SearchTerm>],
+        completions: &[Vec<String>],
+        exclude: &[SearchTerm],
+    ) -> WritingsResult<Vec<(String, f32)>> {
+        let mut alternatives = vec![];
+        for (group, completions) in groups.iter().zip(completions) {
+            let query: Box<dyn TantivyQuery> = match group.split_last() {
+                None => Box::new(AllQuery),
+                Some((last, terms)) => {
+                    let mut clauses = terms
+                        .iter()
+                        .map(|term| (Occur::Must, self.term_query(term)))
+                        .collect::<Vec<_>>();
+                    clauses.push((Occur::Must, self.last_term_query(last, completions)));
+                    Box::new(BooleanQuery::new(clauses))
+                }
+            };
+            alternatives.push((Occur::Should, query));
+        }
+        let mut clauses = vec![(
+            Occur::Must,
+            Box::new(BooleanQuery::new(alternatives)) as Box<dyn TantivyQuery>,
+        )];
+        clauses.extend(
+            exclude
+                .iter()
+                .map(|term| (Occur::MustNot, self.term_query(term))),
+        );
+        let query = BooleanQuery::new(clauses);
+
+        let searcher = self.reader.searcher();
+        let limit = (searcher.num_docs() as usize).max(1);
+        searcher
+            .search(&query, &TopDocs::with_limit(limit))?
+            .into_iter()
+            .map(|(score, address): (f32, DocAddress)| {
+                let doc: TantivyDocument = searcher.doc(address)?;
+                let ref_id = doc
+                    .get_first(self.ref_id)
+                    .and_then(|value| value.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                Ok((ref_id, score))
+            })
+            .collect()
+    }
+
+    fn term_query(&self, term: &SearchTerm) -> Box<dyn TantivyQuery> {
+        match term.words() {
+            [word] => Box::new(TermQuery::new(
+                self.term(word),
+                IndexRecordOption::WithFreqs,
+            )),
+            words => Box::new(PhraseQuery::new_with_offset_and_slop(
+                words
+                    .iter()
+                    .enumerate()
+                    .map(|(i, word)| (i, self.term(word)))
+                    .collect(),
+                term.slop(),
+            )),
+        }
+    }
+
+    /// A query for the last term of a group: a word also matches its completions, and words
+    /// within an edit distance that allows for typos in longer words.
+    fn last_term_query(&self, term: &SearchTerm, completions: &[String]) -> Box<dyn TantivyQuery> {
+        let SearchTerm::Word(word) = term else {
+            return self.term_query(term);
+        };
+        let distance = if word.chars().count() > 4 { 1 } else { 0 };
+        let mut clauses: Vec<(Occur, Box<dyn TantivyQuery>)> = vec![
+            (Occur::Should, self.term_query(term)),
+            (
+                Occur::Should,
+                Box::new(FuzzyTermQuery::new(self.term(word), distance, true)),
+            ),
+        ];
+        clauses.extend(completions.iter().map(|completion| {
+            let query = TermQuery::new(self.term(completion), IndexRecordOption::WithFreqs);
+            (Occur::Should, Box::new(query) as Box<dyn TantivyQuery>)
+        }));
+        Box::new(BooleanQuery::new(clauses))
+    }
+
+    fn term(&self, word: &str) -> Term {
+        Term::from_field_text(self.text, word)
+    }
+}
+
+/// What an index was built from, saved in the payload of its commit.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct BuiltFrom {
+    version: String,
+    /// The [`corpus_fingerprint()`] of the Writings indexed.
+    corpus: u64,
+    /// The [`Analyzer::fingerprint()`] of the analyzer of the words.
+    analyzer: u64,
+}
+
+impl BuiltFrom {
+    fn new(analyzer: &Analyzer) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            corpus: corpus_fingerprint(),
+            analyzer: analyzer.fingerprint(),
+        }
+    }
+
+    /// What the index was built from, if it was built by [`TantivyIndex`].
+    fn of(index: &Index) -> WritingsResult<Option<Self>> {
+        Ok(index
+            .load_metas()?
+            .payload
+            .and_then(|payload| serde_json::from_str(&payload).ok()))
+    }
+}
+
+fn schema() -> Schema {
+    let mut builder = Schema::builder();
+    builder.add_text_field("ref_id", STRING | STORED);
+    builder.add_text_field(
+        "text",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default().set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        ),
+    );
+    builder.build()
+}
+
+/// The analyzed words of the text, with their byte offsets and positions.
+fn tokenize(text: &str, analyzer: &Analyzer) -> PreTokenizedString {
+    let tokens = search_word_ranges(text)
+        .into_iter()
+        .map(|range| (crate::text::fold(&text[range.clone()]), range))
+        .filter(|(word, _)| !word.is_empty())
+        .enumerate()
+        .map(|(position, (word, range))| Token {
+            offset_from: range.start,
+            offset_to: range.end,
+            position,
+            text: analyzer.analyze(&word),
+            position_length: 1,
+        })
+        .collect();
+    PreTokenizedString {
+        text: text.to_string(),
+        tokens,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::{SearchEngine, SearchWeights};
+
+    #[test]
+    fn test_tantivy_search() {
+        let analyzer = Analyzer::default();
+        let index = TantivyIndex::in_memory(&analyzer).unwrap();
+        let engine = SearchEngine::with_tantivy(SearchWeights::default(), analyzer, index);
+
+        let phrase = engine.search(r#""ocean of my presence""#).unwrap();
+        let near = engine.search(r#""ocean presence"~3"#).unwrap();
+        assert!(!phrase.is_empty());
+        assert!(near.len() > phrase.len());
+        assert!(
+            phrase
+                .iter()
+                .all(|r| near.iter().any(|n| n.ref_id == r.ref_id))
+        );
+        assert!(near.windows(2).all(|r| r[0].score >= r[1].score));
+
+        let excluded = engine.search(r#""ocean presence"~3 -enchanter"#).unwrap();
+        assert_eq!(excluded.len(), near.len() - 1);
+
+        assert!(!engine.search("mercifu").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_open_or_build() {
+        let directory =
+            std::env::temp_dir().join(format!("writings-test-tantivy-{}", std::process::id()));
+        // The segments of the index, which are new when it is rebuilt.
+        let segments = || {
+            let index = Index::open_in_dir(&directory).unwrap();
+            (
+                index.searchable_segment_ids().unwrap(),
+                BuiltFrom::of(&index).unwrap(),
+            )
+        };
+
+        // An index with another schema is rebuilt.
+        std::fs::create_dir_all(&directory).unwrap();
+        let mut other = Schema::builder();
+        other.add_text_field("other", STRING);
+        Index::create_in_dir(&directory, other.build()).unwrap();
+        let analyzer = Analyzer::default();
+        TantivyIndex::open_or_build(&directory, &analyzer).unwrap();
+        let (built, built_from) = segments();
+        assert_eq!(built_from, Some(BuiltFrom::new(&analyzer)));
+
+        // An index built from the same Writings and analyzer is reused.
+        let index = TantivyIndex::open_or_build(&directory, &analyzer).unwrap();
+        assert_eq!(segments().0, built);
+        assert_eq!(
+            index.reader.searcher().num_docs(),
+            Writings::all().len() as u64
+        );
+
+        // An index built with another analyzer is rebuilt.
+        let analyzer = Analyzer::default().with_synonyms(&["glory", "splendor"]);
+        TantivyIndex::open_or_build(&directory, &analyzer).unwrap();
+        let (rebuilt, built_from) = segments();
+        assert_ne!(rebuilt, built);
+        assert_eq!(built_from, Some(BuiltFrom::new(&analyzer)));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
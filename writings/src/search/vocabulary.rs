@@ -0,0 +1,210 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Bound,
+};
+
+use rapidfuzz::distance::levenshtein;
+use serde::{Deserialize, Serialize};
+
+use super::search_engine::split_into_words;
+use crate::text::{fold, word_ranges, words};
+use crate::{EmbedAllTrait as _, Writings, WritingsTrait as _};
+
+/// The minimum similarity (0.0 to 1.0) of a misspelled word and its correction.
+const MIN_SIMILARITY: f64 = 0.75;
+
+/// Bonus similarity for a transliteration variant, e.g. "Rezvan" for "Riḍván".
+const VARIANT_BONUS: f64 = 0.2;
+
+/// The words of all embedded Writings, by their folded forms
+/// (lowercase ASCII letters and digits, see [`fold`]).
+#[derive(Serialize, Deserialize)]
+pub(super) struct Vocabulary {
+    /// The most common spelling of each word, and the number of paragraphs containing it.
+    words: BTreeMap<String, (String, usize)>,
+
+    /// Folded words by their [`skeleton`].
+    skeletons: HashMap<String, Vec<String>>,
+}
+
+impl Vocabulary {
+    pub(super) fn new() -> Self {
+        let mut spellings: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        for w in Writings::all().iter() {
+            let text = w.text();
+            let mut seen = vec![];
+            for word in words(&text) {
+                let folded = fold(word);
+                if !folded.is_empty() && !seen.contains(&folded) {
+                    *spellings
+                        .entry(folded.clone())
+                        .or_default()
+                        .entry(word.to_string())
+                        .or_default() += 1;
+                    seen.push(folded);
+                }
+            }
+        }
+
+        let words = spellings
+            .into_iter()
+            .map(|(folded, spellings)| {
+                let count = spellings.values().sum();
+                let (spelling, _) = spellings
+                    .into_iter()
+                    .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+                    .unwrap_or_default();
+                (folded, (spelling, count))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut skeletons: HashMap<String, Vec<String>> = HashMap::new();
+        for folded in words.keys() {
+            skeletons
+                .entry(skeleton(folded))
+                .or_default()
+                .push(folded.clone());
+        }
+
+        Self { words, skeletons }
+    }
+
+    /// The folded words starting with the folded prefix, in order,
+    /// with their most common spelling and count.
+    pub(super) fn prefixed<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a String, &'a (String, usize))> {
+        self.words
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(move |(word, _)| word.starts_with(prefix))
+    }
+
+    /// Up to `limit` likely intended spellings of a word, best first, or none if the word
+    /// is spelled as in the Writings.
+    ///
+    /// Ignores case, diacritics and apostrophes, so "Bahaullah" is spelled as in the Writings
+    /// ("Bahá’u’lláh"), and prefers transliteration variants with the same [`skeleton`],
+    /// e.g. "Bahaulah" or "Rezvan".
+    pub(super) fn corrections(&self, word: &str, limit: usize) -> Vec<&str> {
+        let folded = fold(word);
+        if folded.is_empty() || folded.chars().all(|c| c.is_ascii_digit()) {
+            return vec![];
+        }
+        if let Some((spelling, _)) = self.words.get(&folded) {
+            return if split_into_words(spelling) == split_into_words(word) {
+                vec![]
+            } else {
+                vec![spelling.as_str()]
+            };
+        }
+
+        let variants = self.skeletons.get(&skeleton(&folded));
+        let len = folded.chars().count();
+        let comparator = levenshtein::BatchComparator::new(folded.chars());
+        let mut candidates = self
+            .words
+            .iter()
+            .filter(|(candidate, _)| candidate.len().abs_diff(len) <= 3)
+            .filter_map(|(candidate, (spelling, count))| {
+                let mut score = comparator.normalized_similarity(candidate.chars());
+                if variants.is_some_and(|variants| variants.contains(candidate)) {
+                    score += VARIANT_BONUS;
+                }
+                (score >= MIN_SIMILARITY).then_some((score, *count, spelling.as_str()))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(b.1.cmp(&a.1)));
+        candidates
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, spelling)| spelling)
+            .collect()
+    }
+
+    /// Up to `limit` corrections of the query, replacing each misspelled word with
+    /// its likely intended spelling, best first; field filters and `OR` are kept as is.
+    pub(super) fn corrected_queries(&self, query: &str, limit: usize) -> Vec<String> {
+        let corrections = word_ranges(query)
+            .filter(|range| {
+                let filter =
+                    query[range.end..].starts_with(':') || query[..range.start].ends_with(':');
+                !filter && &query[range.clone()] != "OR"
+            })
+            .map(|range| {
+                let corrections = self.corrections(&query[range.clone()], limit);
+                (range, corrections)
+            })
+            .filter(|(_, corrections)| !corrections.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut queries: Vec<String> = vec![];
+        for i in 0..limit {
+            // The i-th best correction of each word, or its last one.
+            let mut corrected = query.to_string();
+            for (range, corrections) in corrections.iter().rev() {
+                let correction = corrections[i.min(corrections.len() - 1)];
+                corrected.replace_range(range.clone(), correction);
+            }
+            if corrected != query && !queries.contains(&corrected) {
+                queries.push(corrected);
+            }
+        }
+        queries
+    }
+}
+
+/// A rough key on which transliteration variants of a folded word agree: vowels after the
+/// first letter and doubled letters are dropped, and "dh", "d" and "z", and "w" and "v",
+/// are the same; e.g. "bhlh" for "bahaullah", "bahaulah" and "bahaollah",
+/// and "rzvn" for "ridvan", "rezvan" and "rizwan".
+fn skeleton(folded: &str) -> String {
+    let mut skeleton = String::new();
+    for (i, c) in folded.replace("dh", "z").chars().enumerate() {
+        let c = match c {
+            'd' => 'z',
+            'w' => 'v',
+            c => c,
+        };
+        if (i > 0 && "aeiouy".contains(c)) || skeleton.ends_with(c) {
+            continue;
+        }
+        skeleton.push(c);
+    }
+    skeleton
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skeleton() {
+        for word in ["bahaullah", "bahaulah", "bahaollah"] {
+            assert_eq!(skeleton(word), "bhlh", "{word}");
+        }
+        for word in ["ridvan", "rezvan", "rizwan", "ridhvan"] {
+            assert_eq!(skeleton(word), "rzvn", "{word}");
+        }
+    }
+
+    #[test]
+    fn test_corrected_queries() {
+        let vocabulary = Vocabulary::new();
+        assert_eq!(
+            vocabulary.corrected_queries("Bahaulah", 3)[0],
+            "Bahá’u’lláh"
+        );
+        assert_eq!(
+            vocabulary.corrected_queries("Bahaulah author:bahaullah", 3)[0],
+            "Bahá’u’lláh author:bahaullah"
+        );
+        // Search finds “Bahá’u’lláh” as written without diacritics or apostrophes.
+        assert!(vocabulary.corrected_queries("Bahaullah", 3).is_empty());
+        assert_eq!(
+            vocabulary.corrected_queries("Rezvan OR Rizwan", 3)[0],
+            "Riḍván OR Riḍván"
+        );
+        assert!(vocabulary.corrected_queries("mercy justice", 3).is_empty());
+    }
+}
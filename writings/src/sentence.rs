@@ -0,0 +1,302 @@
+//! Splitting text into sentences, aware of vocatives ("O God!"), ellipses, abbreviations
+//! and quotation punctuation, with stable addresses like `ref_id#s3`.
+
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+/// Abbreviations (lowercase, without the final period) after which a period does not end
+/// a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "a.d", "a.h", "b.e", "cf", "ch", "dr", "e.g", "i.e", "ibid", "mr", "mrs", "ms", "no", "nos",
+    "p", "pp", "st", "viz", "vol", "vols",
+];
+
+/// Closing quotation marks and brackets that belong to the sentence they follow.
+const CLOSING: &[char] = &['”', '’', '"', '\'', ')', ']', '»'];
+
+/// Opening quotation marks and brackets that belong to the sentence they precede.
+const OPENING: &[char] = &['“', '‘', '"', '\'', '(', '[', '«'];
+
+/// The maximum number of words of a vocative, e.g. "O Son of Spirit!", that is part of the
+/// sentence that follows it rather than a sentence of its own.
+const MAX_VOCATIVE_WORDS: usize = 6;
+
+/// The number of words at each end of a long sentence to find it by in a text fragment link.
+const FRAGMENT_WORDS: usize = 4;
+
+/// A sentence of the text of a paragraph, see [`crate::WritingsTrait::sentences()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Sentence {
+    /// The stable address of the sentence: the `ref_id` of the paragraph and the `number`
+    /// of the sentence, e.g. "986635113#s2".
+    pub address: String,
+
+    /// The reference ID of the paragraph:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: String,
+
+    /// The number of the sentence within the paragraph, starting from 1.
+    pub number: usize,
+
+    /// The `char` offset of the sentence in the `text` of the paragraph.
+    pub start: usize,
+
+    /// The `char` offset just past the end of the sentence.
+    pub end: usize,
+
+    /// The text of the sentence.
+    pub text: String,
+}
+
+impl Sentence {
+    /// The sentences of the text of the paragraph with the `ref_id`.
+    pub(crate) fn all_of(ref_id: &str, text: &str) -> Vec<Sentence> {
+        let mut chars = 0;
+        let mut bytes = 0;
+        sentence_ranges(text)
+            .into_iter()
+            .enumerate()
+            .map(|(i, range)| {
+                chars += text[bytes..range.start].chars().count();
+                let start = chars;
+                chars += text[range.clone()].chars().count();
+                bytes = range.end;
+                Sentence {
+                    address: format!("{ref_id}#s{}", i + 1),
+                    ref_id: ref_id.to_string(),
+                    number: i + 1,
+                    start,
+                    end: chars,
+                    text: text[range].to_string(),
+                }
+            })
+            .collect()
+    }
+
+    /// Find the sentence of the embedded Writings at the address, e.g. "986635113#s2".
+    ///
+    /// ```
+    /// use writings::Sentence;
+    ///
+    /// let sentence = Sentence::find("986635113#s1").unwrap();
+    /// assert!(sentence.text.starts_with("My first counsel is this"));
+    /// assert!(Sentence::find("986635113#s99").is_none());
+    /// ```
+    #[cfg(feature = "embed-all")]
+    pub fn find(address: &str) -> Option<Sentence> {
+        use crate::{EmbedAllTrait as _, Writings, WritingsTrait as _};
+
+        let (ref_id, number) = address.split_once("#s")?;
+        let number = number.parse::<usize>().ok()?.checked_sub(1)?;
+        Writings::all_map()
+            .get(ref_id)?
+            .sentences()
+            .into_iter()
+            .nth(number)
+    }
+
+    /// A link to the sentence in the Bahá’í Reference Library that highlights it in browsers
+    /// supporting text fragments:
+    /// <https://www.bahai.org/r/`ref_id`#:~:text=`start`,`end`>
+    pub fn url(&self) -> String {
+        let words = self.text.split_whitespace().collect::<Vec<_>>();
+        let fragment = match words.len() > 2 * FRAGMENT_WORDS {
+            true => format!(
+                "{},{}",
+                percent_encode(&words[..FRAGMENT_WORDS].join(" ")),
+                percent_encode(&words[words.len() - FRAGMENT_WORDS..].join(" "))
+            ),
+            false => percent_encode(&words.join(" ")),
+        };
+        format!("https://www.bahai.org/r/{}#:~:text={fragment}", self.ref_id)
+    }
+}
+
+/// The byte ranges of the sentences of the text that contain any words, trimmed.
+///
+/// A sentence ends with a period, question or exclamation mark or ellipsis (and any closing
+/// quotation marks) followed by a space and a capital, unless the period ends an abbreviation
+/// or initial. A short vocative like "O Son of Spirit!" begins the sentence that follows.
+///
+/// ```
+/// use writings::sentence_ranges;
+///
+/// let text = "O Son of Spirit! My first counsel is this: Possess a pure heart. \
+///     Glorified art Thou, O God! for Thou hast “heard my call.” I… and Dr. Esslemont.";
+/// let sentences = sentence_ranges(text)
+///     .into_iter()
+///     .map(|range| &text[range])
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     sentences,
+///     [
+///         "O Son of Spirit! My first counsel is this: Possess a pure heart.",
+///         "Glorified art Thou, O God! for Thou hast “heard my call.”",
+///         "I… and Dr. Esslemont.",
+///     ]
+/// );
+/// ```
+pub fn sentence_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if !matches!(c, '.' | '!' | '?' | '…') {
+            continue;
+        }
+        let mut end = i + c.len_utf8();
+        let mut periods = usize::from(c == '.');
+        while let Some(&(j, next)) = chars.peek() {
+            if matches!(next, '.' | '!' | '?' | '…') || CLOSING.contains(&next) {
+                periods += usize::from(next == '.');
+                end = j + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if is_sentence_end(text, i, end, c == '.' && periods == 1) {
+            ranges.push(start..end);
+            start = end;
+        }
+    }
+    ranges.push(start..text.len());
+
+    let ranges = ranges.into_iter().filter_map(|range| {
+        let sentence = &text[range.clone()];
+        let start = range.start + sentence.len() - sentence.trim_start().len();
+        let range = start..start + sentence.trim().len();
+        text[range.clone()]
+            .chars()
+            .any(char::is_alphanumeric)
+            .then_some(range)
+    });
+
+    // Join each vocative to the sentence that follows it.
+    let mut sentences: Vec<Range<usize>> = vec![];
+    let mut vocatives: Option<Range<usize>> = None;
+    for range in ranges {
+        let start = vocatives
+            .take()
+            .map_or(range.start, |vocatives| vocatives.start);
+        match is_vocative(&text[range.clone()]) {
+            true => vocatives = Some(start..range.end),
+            false => sentences.push(start..range.end),
+        }
+    }
+    sentences.extend(vocatives);
+    sentences
+}
+
+/// Whether the terminal punctuation at `i..end` (including any closing quotation marks)
+/// ends a sentence.
+fn is_sentence_end(text: &str, i: usize, end: usize, period: bool) -> bool {
+    let after = &text[end..];
+    if !after.starts_with(char::is_whitespace) {
+        return after.is_empty();
+    }
+    let next = after
+        .trim_start()
+        .trim_start_matches(OPENING)
+        .chars()
+        .next();
+    if next.is_none_or(|c| !(c.is_uppercase() || c.is_numeric())) {
+        return next.is_none();
+    }
+    if !period {
+        return true;
+    }
+    let word = text[..i]
+        .rsplit(|c: char| c.is_whitespace() || OPENING.contains(&c))
+        .next()
+        .unwrap_or_default();
+    let is_initial = word.chars().count() == 1 && word.chars().all(char::is_uppercase);
+    !is_initial && !ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+/// Whether the sentence is a short exclamation beginning with "O", e.g. "O my God!".
+fn is_vocative(sentence: &str) -> bool {
+    let sentence = sentence.trim_start_matches(OPENING);
+    sentence.starts_with("O ")
+        && sentence.trim_end_matches(CLOSING).ends_with('!')
+        && sentence.split_whitespace().count() <= MAX_VOCATIVE_WORDS
+}
+
+/// Percent-encode all but ASCII letters and digits, for a text fragment.
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b.is_ascii_alphanumeric() {
+            true => (b as char).to_string(),
+            false => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentences(text: &str) -> Vec<&str> {
+        sentence_ranges(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_sentence_ranges() {
+        assert_eq!(
+            sentences(
+                "O my God! O my God! O my God! Unite the hearts of Thy servants. \
+                 Thou art the Mighty."
+            ),
+            [
+                "O my God! O my God! O my God! Unite the hearts of Thy servants.",
+                "Thou art the Mighty."
+            ]
+        );
+        assert_eq!(
+            sentences("He said, “Arise!” And they arose. “Is it not?” he asked."),
+            [
+                "He said, “Arise!”",
+                "And they arose.",
+                "“Is it not?” he asked."
+            ]
+        );
+        assert_eq!(
+            sentences("The Báb (1819–1850 A.D.) wrote… Then cf. p. 12. J. E. Esslemont..."),
+            [
+                "The Báb (1819–1850 A.D.) wrote…",
+                "Then cf. p. 12.",
+                "J. E. Esslemont..."
+            ]
+        );
+        assert_eq!(sentences("O God!"), ["O God!"]);
+        assert_eq!(sentences("  …  "), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_sentence() {
+        let text = "O Son of Being! Love Me, that I may love thee. If thou lovest Me not, \
+                    My love can in no wise reach thee.";
+        let sentences = Sentence::all_of("123", text);
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[1].address, "123#s2");
+        assert_eq!(
+            text.chars()
+                .skip(sentences[1].start)
+                .take(sentences[1].end - sentences[1].start)
+                .collect::<String>(),
+            sentences[1].text
+        );
+        assert_eq!(
+            sentences[1].url(),
+            "https://www.bahai.org/r/123#:~:text=If%20thou%20lovest%20Me,no%20wise%20reach%20thee%2E"
+        );
+    }
+}
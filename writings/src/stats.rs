@@ -0,0 +1,364 @@
+#![cfg(feature = "stats")]
+//! Word and sentence statistics, n-grams, collocations and distinctive terms of any
+//! selection of the embedded Writings: a paragraph, a Work, an [`crate::Author`], or all.
+//!
+//! ```
+//! use writings::{Author, Query, stats::Stats};
+//!
+//! let bahaullah = Stats::of(&Query::new().author(Author::Bahaullah));
+//! let summary = bahaullah.summary();
+//! assert!(summary.words > summary.vocabulary);
+//! assert!(summary.reading_minutes > 60.0);
+//!
+//! let others = Stats::except(&Query::new().author(Author::Bahaullah));
+//! let distinctive = bahaullah.distinctive_terms(&others, 10);
+//! assert_eq!(distinctive.len(), 10);
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::text::{sentences, words};
+use crate::{EmbedAllTrait as _, Query, Writings, WritingsTrait as _};
+
+/// The average silent reading speed of English, in words per minute.
+pub const WORDS_PER_MINUTE: f64 = 200.0;
+
+/// Function words that do not begin or end an n-gram or collocation, e.g. "the".
+/// Archaic pronouns ("thee", "thy") are kept: they distinguish the style of the Writings.
+const STOPWORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at", "be",
+    "been", "before", "being", "but", "by", "can", "could", "did", "do", "does", "for", "from",
+    "had", "has", "have", "he", "her", "hers", "him", "his", "how", "i", "if", "in", "into", "is",
+    "it", "its", "may", "me", "might", "must", "my", "no", "nor", "not", "of", "on", "one", "or",
+    "our", "shall", "she", "should", "so", "such", "than", "that", "the", "their", "them", "then",
+    "there", "these", "they", "this", "those", "to", "upon", "us", "was", "we", "were", "what",
+    "when", "which", "who", "whom", "will", "with", "would", "you", "your",
+];
+
+/// Summary statistics of some Writings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct TextStats {
+    pub paragraphs: usize,
+    pub sentences: usize,
+    pub words: usize,
+    /// The number of distinct words, ignoring case.
+    pub vocabulary: usize,
+    /// The share of the distinct words that occur only once.
+    pub hapax_ratio: f64,
+    pub sentence_lengths: SentenceLengths,
+    /// At [`WORDS_PER_MINUTE`].
+    pub reading_minutes: f64,
+}
+
+/// The distribution of sentence lengths, in words.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct SentenceLengths {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: usize,
+    /// The number of sentences of each length, by ascending length.
+    pub histogram: Vec<LengthCount>,
+}
+
+/// The number of sentences with a length.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct LengthCount {
+    pub words: usize,
+    pub count: usize,
+}
+
+/// The number of occurrences of a word or n-gram.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct TermCount {
+    /// Lowercase words separated by spaces.
+    pub term: String,
+    pub count: usize,
+}
+
+/// Two words that occur together more often than by chance, e.g. "mercy seat".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Collocation {
+    /// The two lowercase words, separated by a space.
+    pub term: String,
+    pub count: usize,
+    /// Pointwise mutual information, in bits: how much more often the words occur
+    /// together than if they were independent.
+    pub score: f64,
+}
+
+/// A word used more in some Writings than in others.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct DistinctiveTerm {
+    pub term: String,
+    pub count: usize,
+    /// The number of occurrences in the other Writings.
+    pub other_count: usize,
+    /// Log-likelihood (G²): higher is more distinctive.
+    pub score: f64,
+}
+
+/// The lowercase words of each sentence of some Writings, to compute statistics of.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    paragraphs: usize,
+    sentences: Vec<Vec<String>>,
+}
+
+impl Stats {
+    /// Statistics of the text of the Writings.
+    pub fn new<'a>(writings: impl IntoIterator<Item = &'a Writings>) -> Self {
+        let mut stats = Self::default();
+        for w in writings {
+            stats.paragraphs += 1;
+            stats.sentences.extend(
+                sentences(&w.text())
+                    .map(|sentence| words(sentence).map(str::to_lowercase).collect::<Vec<_>>())
+                    .filter(|words| !words.is_empty()),
+            );
+        }
+        stats
+    }
+
+    /// Statistics of the Writings matching the query, e.g. of a Work or Author.
+    pub fn of(query: &Query) -> Self {
+        Self::new(&query.execute())
+    }
+
+    /// Statistics of the Writings not matching the query, to compare with [`Stats::of`].
+    pub fn except(query: &Query) -> Self {
+        Self::new(Writings::all().iter().filter(|w| !query.matches(w)))
+    }
+
+    /// Statistics of all embedded Writings.
+    pub fn all() -> Self {
+        Self::new(Writings::all().iter())
+    }
+
+    /// Counts of paragraphs, sentences and words, vocabulary, sentence lengths
+    /// and reading time.
+    pub fn summary(&self) -> TextStats {
+        let frequencies = self.frequencies();
+        let words = frequencies.values().sum::<usize>();
+        let hapaxes = frequencies.values().filter(|&&count| count == 1).count();
+        TextStats {
+            paragraphs: self.paragraphs,
+            sentences: self.sentences.len(),
+            words,
+            vocabulary: frequencies.len(),
+            hapax_ratio: ratio(hapaxes, frequencies.len()),
+            sentence_lengths: self.sentence_lengths(),
+            reading_minutes: words as f64 / WORDS_PER_MINUTE,
+        }
+    }
+
+    fn sentence_lengths(&self) -> SentenceLengths {
+        let mut lengths = self.sentences.iter().map(Vec::len).collect::<Vec<_>>();
+        lengths.sort_unstable();
+        let (Some(&min), Some(&max)) = (lengths.first(), lengths.last()) else {
+            return SentenceLengths::default();
+        };
+        let mut histogram: Vec<LengthCount> = vec![];
+        for &words in &lengths {
+            match histogram.last_mut() {
+                Some(last) if last.words == words => last.count += 1,
+                _ => histogram.push(LengthCount { words, count: 1 }),
+            }
+        }
+        SentenceLengths {
+            min,
+            max,
+            mean: ratio(lengths.iter().sum(), lengths.len()),
+            median: lengths[lengths.len() / 2],
+            histogram,
+        }
+    }
+
+    /// The number of occurrences of each lowercase word.
+    pub fn frequencies(&self) -> HashMap<String, usize> {
+        self.ngram_counts(1)
+    }
+
+    /// The `limit` most frequent sequences of `n` words within a sentence, most frequent
+    /// first, that neither begin nor end with a function word such as "the".
+    pub fn ngrams(&self, n: usize, limit: usize) -> Vec<TermCount> {
+        let mut ngrams = self
+            .ngram_counts(n)
+            .into_iter()
+            .filter(|(ngram, _)| {
+                let first = ngram.split(' ').next().unwrap_or_default();
+                let last = ngram.rsplit(' ').next().unwrap_or_default();
+                !is_stopword(first) && !is_stopword(last)
+            })
+            .map(|(term, count)| TermCount { term, count })
+            .collect::<Vec<_>>();
+        ngrams.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.term.cmp(&b.term)));
+        ngrams.truncate(limit);
+        ngrams
+    }
+
+    /// The `limit` pairs of adjacent words (neither a function word) occurring at least
+    /// `min_count` times, by descending pointwise mutual information.
+    pub fn collocations(&self, min_count: usize, limit: usize) -> Vec<Collocation> {
+        let words = self.frequencies();
+        let total = words.values().sum::<usize>() as f64;
+        let mut collocations = self
+            .ngram_counts(2)
+            .into_iter()
+            .filter(|(_, count)| *count >= min_count.max(1))
+            .filter_map(|(term, count)| {
+                let (a, b) = term.split_once(' ')?;
+                if is_stopword(a) || is_stopword(b) {
+                    return None;
+                }
+                let expected = words[a] as f64 * words[b] as f64 / total;
+                let score = (count as f64 / expected).log2();
+                Some(Collocation { term, count, score })
+            })
+            .collect::<Vec<_>>();
+        collocations.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| b.count.cmp(&a.count))
+                .then_with(|| a.term.cmp(&b.term))
+        });
+        collocations.truncate(limit);
+        collocations
+    }
+
+    /// The `limit` words most over-represented in these Writings compared with the `other`
+    /// (e.g. one Author compared with the others), by descending log-likelihood.
+    /// Function words are not included.
+    pub fn distinctive_terms(&self, other: &Stats, limit: usize) -> Vec<DistinctiveTerm> {
+        let (words, other_words) = (self.frequencies(), other.frequencies());
+        let total = words.values().sum::<usize>() as f64;
+        let other_total = other_words.values().sum::<usize>() as f64;
+        let mut terms = words
+            .into_iter()
+            .filter(|(term, _)| !is_stopword(term))
+            .filter_map(|(term, count)| {
+                let other_count = other_words.get(&term).copied().unwrap_or_default();
+                let (a, b) = (count as f64, other_count as f64);
+                let expected = total * (a + b) / (total + other_total);
+                let other_expected = other_total * (a + b) / (total + other_total);
+                if a <= expected {
+                    return None;
+                }
+                let g2 = |observed: f64, expected: f64| match observed {
+                    0.0 => 0.0,
+                    _ => observed * (observed / expected).ln(),
+                };
+                let score = 2.0 * (g2(a, expected) + g2(b, other_expected));
+                Some(DistinctiveTerm {
+                    term,
+                    count,
+                    other_count,
+                    score,
+                })
+            })
+            .collect::<Vec<_>>();
+        terms.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.term.cmp(&b.term))
+        });
+        terms.truncate(limit);
+        terms
+    }
+
+    /// The number of occurrences of each sequence of `n` words within a sentence,
+    /// separated by spaces.
+    fn ngram_counts(&self, n: usize) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        if n == 0 {
+            return counts;
+        }
+        for sentence in &self.sentences {
+            for ngram in sentence.windows(n) {
+                *counts.entry(ngram.join(" ")).or_default() += 1;
+            }
+        }
+        counts
+    }
+}
+
+fn is_stopword(word: &str) -> bool {
+    STOPWORDS.binary_search(&word).is_ok()
+}
+
+fn ratio(a: usize, b: usize) -> f64 {
+    match b {
+        0 => 0.0,
+        b => a as f64 / b as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HiddenWord;
+
+    #[test]
+    fn test_stopwords_sorted() {
+        assert!(STOPWORDS.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_stats() {
+        let hidden_words = HiddenWord::all()
+            .iter()
+            .cloned()
+            .map(Writings::from)
+            .collect::<Vec<_>>();
+        let stats = Stats::new(&hidden_words);
+        let summary = stats.summary();
+        assert_eq!(summary.paragraphs, hidden_words.len());
+        assert_eq!(
+            summary
+                .sentence_lengths
+                .histogram
+                .iter()
+                .map(|h| h.count)
+                .sum::<usize>(),
+            summary.sentences
+        );
+        assert_eq!(
+            summary
+                .sentence_lengths
+                .histogram
+                .iter()
+                .map(|h| h.words * h.count)
+                .sum::<usize>(),
+            summary.words
+        );
+
+        let ngrams = stats.ngrams(3, 5);
+        assert_eq!(ngrams.len(), 5);
+        assert!(ngrams.windows(2).all(|n| n[0].count >= n[1].count));
+        assert!(ngrams.iter().all(|n| n.term.split(' ').count() == 3));
+        assert!(!stats.ngrams(1, 50).iter().any(|n| n.term == "the"));
+
+        let collocations = stats.collocations(3, 10);
+        assert!(collocations.iter().all(|c| c.count >= 3 && c.score > 0.0));
+    }
+}
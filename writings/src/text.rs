@@ -0,0 +1,212 @@
+#![cfg(feature = "embed-all")]
+//! Splitting text into words and sentences, folding words, and finding phrases by their
+//! folded words, shared by [`crate::search`], [`crate::stats`], the shared passages of
+//! [`crate::Writings::appears_in()`], the glossary and the gazetteer.
+
+use std::{collections::HashMap, ops::Range, sync::LazyLock};
+
+use regex::Regex;
+
+/// Words, including their apostrophes and hyphens, e.g. Bahá’u’lláh.
+static WORD_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\w+(?:[’'‑-]\w+)*").unwrap());
+
+/// The words of the text, with the opening ‘ of names like ‘Abdu’l-Bahá.
+#[cfg(any(feature = "search", feature = "stats"))]
+pub(crate) fn words(text: &str) -> impl Iterator<Item = &str> {
+    word_ranges(text).map(|range| &text[range])
+}
+
+/// The byte ranges of the words of the text, see [`words`].
+pub(crate) fn word_ranges(text: &str) -> impl Iterator<Item = Range<usize>> {
+    WORD_REGEX
+        .find_iter(text)
+        .map(|m| match text[..m.start()].strip_suffix('‘') {
+            Some(before) if m.as_str().contains('’') => before.len()..m.end(),
+            _ => m.range(),
+        })
+}
+
+/// Lowercase ASCII letters only, so `abdulbaha`, `Abdu’l-Bahá` and `‘Abdu’l‑Bahá` all compare equal.
+pub(crate) fn fold(s: &str) -> String {
+    crate::normalize::fold(s)
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Phrases (e.g. spellings of names) to find in texts by their folded words, ignoring case,
+/// typography and diacritics.
+pub(crate) struct Phrases {
+    /// The folded words of each phrase, whether it is capitalized and its value, by the
+    /// first folded word and longest first.
+    phrases: HashMap<String, Vec<(Vec<String>, bool, usize)>>,
+}
+
+impl Phrases {
+    /// The phrases, each with a value, e.g. the position of the name it is a spelling of.
+    pub(crate) fn new<'a>(phrases: impl IntoIterator<Item = (&'a str, usize)>) -> Self {
+        let mut by_first: HashMap<String, Vec<(Vec<String>, bool, usize)>> = HashMap::new();
+        for (phrase, value) in phrases {
+            let words = folded_words(phrase);
+            let capitalized = phrase
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .starts_with(char::is_uppercase);
+            if let Some(first) = words.first() {
+                by_first
+                    .entry(first.clone())
+                    .or_default()
+                    .push((words, capitalized, value));
+            }
+        }
+        for candidates in by_first.values_mut() {
+            candidates.sort_by_key(|(words, _, _)| std::cmp::Reverse(words.len()));
+        }
+        Self { phrases: by_first }
+    }
+
+    /// The byte ranges of the phrases in the text, in order, each with the value of the
+    /// longest phrase there, without a possessive "’s". With `proper`, a capitalized phrase
+    /// only matches capitalized text, so "Lot" is not "lot".
+    pub(crate) fn find_in(&self, text: &str, proper: bool) -> Vec<(Range<usize>, usize)> {
+        let words = word_ranges(text)
+            .map(|range| {
+                let end = range.end - possessive_len(&text[range.clone()]);
+                (range.start..end, fold(&text[range.start..end]))
+            })
+            .collect::<Vec<_>>();
+
+        let mut found = vec![];
+        let mut i = 0;
+        while i < words.len() {
+            let capitalized = text[words[i].0.clone()]
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .starts_with(char::is_uppercase);
+            let phrase = self.phrases.get(&words[i].1).and_then(|candidates| {
+                candidates.iter().find(|(phrase, phrase_capitalized, _)| {
+                    words.len() - i >= phrase.len()
+                        && (!proper || capitalized || !phrase_capitalized)
+                        && phrase
+                            .iter()
+                            .zip(&words[i..])
+                            .all(|(folded, (_, word))| folded == word)
+                })
+            });
+            match phrase {
+                Some((phrase, _, value)) => {
+                    found.push((words[i].0.start..words[i + phrase.len() - 1].0.end, *value));
+                    i += phrase.len();
+                }
+                None => i += 1,
+            }
+        }
+        found
+    }
+}
+
+/// The folded words of the phrase, see [`fold`].
+pub(crate) fn folded_words(phrase: &str) -> Vec<String> {
+    word_ranges(phrase)
+        .map(|range| fold(&phrase[range]))
+        .collect()
+}
+
+/// The length in bytes of a possessive "’s" or "'s" at the end of the word.
+pub(crate) fn possessive_len(word: &str) -> usize {
+    ["’s", "'s"]
+        .iter()
+        .find(|suffix| word.len() > suffix.len() && word.ends_with(*suffix))
+        .map_or(0, |suffix| suffix.len())
+}
+
+/// The sentences of the text that contain any words, see [`crate::sentence_ranges`].
+#[cfg(any(feature = "search", feature = "stats"))]
+pub(crate) fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    crate::sentence_ranges(text)
+        .into_iter()
+        .map(|range| &text[range])
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Check a list of items found by their spellings, e.g. the glossary, given the `id` and
+    /// spellings of each item and a function to find the `id` of an item by name: the ids are
+    /// in order, each item is found by its id and by each of its spellings, no spelling is
+    /// the spelling of another item, and each spelling is found in text as the item.
+    pub(crate) fn assert_spellings<'a>(
+        items: &[(&'a str, Vec<&'a str>)],
+        find: impl Fn(&str) -> Option<&'a str>,
+    ) {
+        assert!(items.windows(2).all(|w| w[0].0 < w[1].0));
+        let mut spellings = HashMap::new();
+        for (id, names) in items {
+            assert_eq!(find(id), Some(*id));
+            for name in names {
+                assert_eq!(find(name), Some(*id), "{name}");
+                assert_eq!(spellings.insert(folded_words(name), id), None, "{name}");
+            }
+        }
+
+        let phrases =
+            Phrases::new(items.iter().enumerate().flat_map(|(position, (_, names))| {
+                names.iter().map(move |name| (*name, position))
+            }));
+        for (position, (_, names)) in items.iter().enumerate() {
+            for name in names {
+                let found = phrases.find_in(name, true);
+                assert_eq!(found.len(), 1, "{name}");
+                assert_eq!(found[0].1, position, "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_phrases() {
+        let phrases = Phrases::new([
+            ("Mount Carmel", 0),
+            ("Carmel", 1),
+            ("‘Akká", 2),
+            ("Lot", 3),
+            ("ark", 4),
+        ]);
+        let found = |text: &'static str, proper: bool| {
+            phrases
+                .find_in(text, proper)
+                .into_iter()
+                .map(|(range, value)| (&text[range], value))
+                .collect::<Vec<_>>()
+        };
+
+        // The longest phrase at each word.
+        assert_eq!(
+            found("On Mount Carmel, Carmel.", true),
+            [("Mount Carmel", 0), ("Carmel", 1)]
+        );
+        // Without a possessive.
+        assert_eq!(found("Carmel’s slopes", true), [("Carmel", 1)]);
+        assert_eq!(found("the Ark's", true), [("Ark", 4)]);
+        // Ignoring case, typography and diacritics.
+        assert_eq!(
+            found("'Akka, AKKA and ‘Akká", true),
+            [("Akka", 2), ("AKKA", 2), ("Akká", 2)]
+        );
+        // A capitalized phrase only matches capitalized text when `proper`.
+        assert_eq!(found("the lot of Lot", true), [("Lot", 3)]);
+        assert_eq!(found("the lot of Lot", false), [("lot", 3), ("Lot", 3)]);
+        // Only whole words.
+        assert_eq!(found("Carmelites in Akkad", true), []);
+    }
+
+    #[test]
+    fn test_words() {
+        let text = "‘Abdu’l-Bahá and Bahá’u’lláh’s ‘words’";
+        assert_eq!(
+            word_ranges(text)
+                .map(|range| &text[range])
+                .collect::<Vec<_>>(),
+            ["‘Abdu’l-Bahá", "and", "Bahá’u’lláh’s", "words"]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+/// A table of contents entry: a Work, a part or section of a Work, or a single
+/// selection within it (a Gleaning, a prayer, a Hidden Word, etc.), with its nested entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(utoipa::ToSchema),
+    schema(
+        example = json!(Toc {
+            title: "XIX".to_string(),
+            ref_id: Some("520658496".to_string()),
+            number: Some(19),
+            paragraphs: 5,
+            children: vec![],
+        }),
+    ),
+)]
+pub struct Toc {
+    /// The title of the entry, e.g. "Part Two: From the Persian" or "XIX".
+    pub title: String,
+
+    /// The reference ID of the first paragraph within the entry:
+    /// <https://www.bahai.org/r/`ref_id`>
+    pub ref_id: Option<String>,
+
+    /// The number of the selection (Gleaning, prayer, Hidden Word, etc.), if numbered.
+    pub number: Option<u32>,
+
+    /// The total number of paragraphs (records) within the entry, including all nested entries.
+    pub paragraphs: u32,
+
+    /// The nested entries, in document order.
+    #[cfg_attr(feature = "utoipa", schema(no_recursion))]
+    pub children: Vec<Toc>,
+}
+
+impl Toc {
+    /// Create an empty entry with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ref_id: None,
+            number: None,
+            paragraphs: 0,
+            children: vec![],
+        }
+    }
+
+    /// Find the first entry (depth-first, including `self`) containing the paragraph
+    /// with the given `ref_id` as its first paragraph.
+    pub fn find(&self, ref_id: &str) -> Option<&Toc> {
+        if self.ref_id.as_deref() == Some(ref_id) {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(ref_id))
+    }
+
+    /// Count one paragraph with `ref_id` under the nested entries given by `path`
+    /// (`(title, number)` from the outermost to the innermost), creating them as needed.
+    ///
+    /// Paragraphs must be inserted in document order: an entry is only reused
+    /// if it is the last child of its parent.
+    #[cfg(feature = "_visitors")]
+    pub(crate) fn insert(&mut self, path: &[(String, Option<u32>)], ref_id: &str) {
+        if self.ref_id.is_none() {
+            self.ref_id = Some(ref_id.to_string());
+        }
+        self.paragraphs += 1;
+
+        let Some(((title, number), rest)) = path.split_first() else {
+            return;
+        };
+
+        let is_current = self
+            .children
+            .last()
+            .is_some_and(|child| child.title == *title && child.number == *number);
+        if !is_current {
+            let mut child = Toc::new(title.clone());
+            child.number = *number;
+            self.children.push(child);
+        }
+
+        if let Some(child) = self.children.last_mut() {
+            child.insert(rest, ref_id);
+        }
+    }
+}
+
+/// The first `count` words of `text`, followed by an ellipsis if truncated,
+/// used to title selections that have no title of their own, such as prayers.
+#[cfg(feature = "_visitors")]
+pub(crate) fn opening_words(text: &str, count: usize) -> String {
+    let mut words = text.split_whitespace();
+    let opening = words.by_ref().take(count).collect::<Vec<_>>().join(" ");
+    if words.next().is_some() {
+        format!("{opening}…")
+    } else {
+        opening
+    }
+}
+
+#[cfg(all(test, feature = "_visitors"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut toc = Toc::new("Work");
+        let a = ("A".to_string(), None);
+        let b = ("B".to_string(), None);
+        toc.insert(&[a.clone(), ("1".to_string(), Some(1))], "1a");
+        toc.insert(&[a.clone(), ("1".to_string(), Some(1))], "1b");
+        toc.insert(&[b.clone(), ("2".to_string(), Some(2))], "2a");
+        toc.insert(&[a.clone(), ("3".to_string(), Some(3))], "3a");
+
+        assert_eq!(toc.paragraphs, 4);
+        assert_eq!(toc.ref_id.as_deref(), Some("1a"));
+        let titles = toc
+            .children
+            .iter()
+            .map(|c| c.title.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, ["A", "B", "A"]);
+        assert_eq!(toc.children[0].children[0].paragraphs, 2);
+        assert_eq!(toc.find("3a").map(|t| t.title.as_str()), Some("A"));
+    }
+
+    #[test]
+    fn test_opening_words() {
+        assert_eq!(opening_words("O God! Guide me.", 2), "O God!…");
+        assert_eq!(opening_words("O God!", 2), "O God!");
+    }
+}
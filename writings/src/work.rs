@@ -0,0 +1,655 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator as _};
+
+use crate::{Author, BookTitle, PrayerSource, TabletSource};
+
+/// A Work of the Bahá’í Writings: a book, or a compilation of prayers or Tablets,
+/// as published in the <a href="https://www.bahai.org/library/" target="_blank">Bahá’í Reference Library</a>.
+/// See [`Work::info()`] for the registry of metadata about each Work.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize, strum::Display,
+)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(writings_macros::ToEnumSchema),
+    schema(descriptions = to_string)
+)]
+pub enum Work {
+    // The Báb
+    #[strum(serialize = "Selections from the Writings of the Báb")]
+    SelectionsFromTheWritingsOfTheBab,
+
+    // Bahá’u’lláh
+    #[strum(serialize = "The Call of the Divine Beloved")]
+    CallOfTheDivineBeloved,
+    #[strum(serialize = "Days of Remembrance")]
+    DaysOfRemembrance,
+    #[strum(serialize = "Epistle to the Son of the Wolf")]
+    EpistleToTheSonOfTheWolf,
+    #[strum(serialize = "Gleanings from the Writings of Bahá’u’lláh")]
+    Gleanings,
+    #[strum(serialize = "The Hidden Words")]
+    HiddenWords,
+    #[strum(serialize = "Kitáb-i-Aqdas")]
+    KitabiAqdas,
+    #[strum(serialize = "Kitáb-i-Íqán")]
+    KitabiIqan,
+    #[strum(serialize = "Prayers and Meditations")]
+    PrayersAndMeditations,
+    #[strum(serialize = "Summons of the Lord of Hosts")]
+    SummonsOfTheLordOfHosts,
+    #[strum(serialize = "The Tabernacle of Unity")]
+    TabernacleOfUnity,
+    #[strum(serialize = "Tablets of Bahá’u’lláh")]
+    TabletsOfBahaullah,
+    #[strum(serialize = "Additional Tablets and Extracts from Tablets Revealed by Bahá’u’lláh")]
+    AdditionalTabletsAndExtractsBahaullah,
+
+    // ‘Abdu’l‑Bahá
+    #[strum(serialize = "Light of the World: Selected Tablets of ‘Abdu’l‑Bahá")]
+    LightOfTheWorld,
+    #[strum(serialize = "Memorials of the Faithful")]
+    MemorialsOfTheFaithful,
+    #[strum(serialize = "Paris Talks")]
+    ParisTalks,
+    #[strum(serialize = "The Promulgation of Universal Peace")]
+    PromulgationOfUniversalPeace,
+    #[strum(serialize = "The Secret of Divine Civilization")]
+    SecretOfDivineCivilization,
+    #[strum(serialize = "Selections from the Writings of ‘Abdu’l‑Bahá")]
+    SelectionsFromTheWritingsOfAbdulBaha,
+    #[strum(serialize = "Some Answered Questions")]
+    SomeAnsweredQuestions,
+    #[strum(serialize = "Tablet to Dr. Auguste Forel")]
+    TabletToDrAugusteForel,
+    #[strum(serialize = "Tablets of the Divine Plan")]
+    TabletsOfTheDivinePlan,
+    #[strum(serialize = "Tablets to The Hague")]
+    TabletsToTheHague,
+    #[strum(serialize = "A Traveler’s Narrative")]
+    ATravelersNarrative,
+    #[strum(serialize = "Twelve Table Talks given by ‘Abdu’l‑Bahá in ‘Akká")]
+    TwelveTableTalks,
+    #[strum(serialize = "Will and Testament of ‘Abdu’l‑Bahá")]
+    WillAndTestamentOfAbdulBaha,
+    #[strum(serialize = "Additional Tablets, Extracts, and Talks by ‘Abdu’l‑Bahá")]
+    AdditionalTabletsExtractsAndTalksAbdulBaha,
+
+    // Prayers
+    #[strum(serialize = "Bahá’í Prayers")]
+    BahaiPrayers,
+    #[strum(serialize = "Additional Prayers Revealed by Bahá’u’lláh")]
+    AdditionalPrayersBahaullah,
+    #[strum(serialize = "Additional Prayers Revealed by ‘Abdu’l‑Bahá")]
+    AdditionalPrayersAbdulBaha,
+    #[strum(serialize = "Twenty-six Prayers Revealed by ‘Abdu’l‑Bahá")]
+    TwentySixPrayersAbdulBaha,
+    #[strum(serialize = "Bahá’í Prayers and Tablets for Children")]
+    PrayersAndTabletsForChildren,
+}
+
+/// Metadata about a [`Work`] from the registry, including the number of records embedded in this crate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(utoipa::ToSchema),
+    schema(
+        example = json!(WorkInfo {
+            work: Work::Gleanings,
+            title: "Gleanings from the Writings of Bahá’u’lláh".to_string(),
+            abbreviation: "GWB".to_string(),
+            author: Some(Author::Bahaullah),
+            translator: Some("Shoghi Effendi".to_string()),
+            publisher: Some("Bahá’í Publishing Trust".to_string()),
+            year: Some(1935),
+            url: "https://www.bahai.org/library/authoritative-texts/bahaullah/gleanings-writings-bahaullah/".to_string(),
+            embedded: true,
+            count: 716,
+        }),
+    ),
+)]
+pub struct WorkInfo {
+    /// The Work this metadata describes.
+    pub work: Work,
+
+    /// The canonical title of the Work.
+    pub title: String,
+
+    /// The customary short abbreviation used when citing the Work, e.g. "GWB".
+    pub abbreviation: String,
+
+    /// The Author of the Work. None or null for compilations from more than one Author.
+    pub author: Option<Author>,
+
+    /// The translator into English, if a single translator is credited.
+    pub translator: Option<String>,
+
+    /// The publisher of the English translation.
+    pub publisher: Option<String>,
+
+    /// The year the English translation was first published.
+    pub year: Option<u32>,
+
+    /// The Work in the Bahá’í Reference Library at <https://www.bahai.org/library/>.
+    pub url: String,
+
+    /// Whether any records of the Work are embedded in this crate.
+    pub embedded: bool,
+
+    /// The number of records (paragraphs, Hidden Words, etc.) of the Work embedded in this crate.
+    pub count: usize,
+}
+
+struct WorkData {
+    abbreviation: &'static str,
+    author: Option<Author>,
+    translator: Option<&'static str>,
+    publisher: Option<&'static str>,
+    year: Option<u32>,
+    /// Path below <https://www.bahai.org/library/authoritative-texts/>.
+    path: &'static str,
+}
+
+const BAHAI_WORLD_CENTRE: Option<&str> = Some("Bahá’í World Centre");
+const BAHAI_PUBLISHING_TRUST: Option<&str> = Some("Bahá’í Publishing Trust");
+const SHOGHI_EFFENDI: Option<&str> = Some("Shoghi Effendi");
+
+impl Work {
+    pub fn title(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        self.data().abbreviation
+    }
+
+    pub fn author(&self) -> Option<Author> {
+        self.data().author
+    }
+
+    pub fn url(&self) -> String {
+        format!(
+            "https://www.bahai.org/library/authoritative-texts/{}/",
+            self.data().path
+        )
+    }
+
+    /// Whether the Work is a compilation of selections or prayers drawn from other Works
+    /// and Tablets, e.g. Gleanings, rather than a Work or Tablets in full.
+    pub fn is_compilation(&self) -> bool {
+        matches!(
+            self,
+            Work::SelectionsFromTheWritingsOfTheBab
+                | Work::Gleanings
+                | Work::PrayersAndMeditations
+                | Work::AdditionalTabletsAndExtractsBahaullah
+                | Work::SelectionsFromTheWritingsOfAbdulBaha
+                | Work::AdditionalTabletsExtractsAndTalksAbdulBaha
+                | Work::BahaiPrayers
+                | Work::PrayersAndTabletsForChildren
+        )
+    }
+
+    /// The registry entry for this Work.
+    pub fn info(&self) -> WorkInfo {
+        let data = self.data();
+        let count = self.count();
+        WorkInfo {
+            work: *self,
+            title: self.title(),
+            abbreviation: data.abbreviation.to_string(),
+            author: data.author,
+            translator: data.translator.map(str::to_string),
+            publisher: data.publisher.map(str::to_string),
+            year: data.year,
+            url: self.url(),
+            embedded: count > 0,
+            count,
+        }
+    }
+
+    /// Find a Work by its abbreviation, case-insensitive, e.g. "gwb".
+    pub fn from_abbreviation(abbreviation: &str) -> Option<Work> {
+        Work::iter().find(|w| w.abbreviation().eq_ignore_ascii_case(abbreviation))
+    }
+
+    /// The number of records of this Work embedded in this crate.
+    pub fn count(&self) -> usize {
+        #[cfg(feature = "_embed-any")]
+        #[allow(unused_imports)]
+        use crate::EmbedAllTrait as _;
+
+        match self {
+            #[cfg(feature = "embed-cdb")]
+            Work::CallOfTheDivineBeloved => crate::CDBParagraph::all().len(),
+            #[cfg(feature = "embed-gleanings")]
+            Work::Gleanings => crate::GleaningsParagraph::all().len(),
+            #[cfg(feature = "embed-hidden-words")]
+            Work::HiddenWords => crate::HiddenWord::all().len(),
+            #[cfg(feature = "embed-meditations")]
+            Work::PrayersAndMeditations => crate::MeditationParagraph::all().len(),
+            #[cfg(feature = "embed-prayers")]
+            Work::BahaiPrayers
+            | Work::AdditionalPrayersBahaullah
+            | Work::AdditionalPrayersAbdulBaha
+            | Work::TwentySixPrayersAbdulBaha
+            | Work::PrayersAndTabletsForChildren => crate::PrayerParagraph::all()
+                .iter()
+                .filter(|p| Work::from(p.source) == *self)
+                .count(),
+            _ => 0,
+        }
+    }
+
+    fn data(&self) -> WorkData {
+        match self {
+            // The Báb
+            Work::SelectionsFromTheWritingsOfTheBab => WorkData {
+                abbreviation: "SWB",
+                author: Some(Author::TheBab),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(1976),
+                path: "the-bab/selections-writings-bab",
+            },
+
+            // Bahá’u’lláh
+            Work::CallOfTheDivineBeloved => WorkData {
+                abbreviation: "CDB",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(2018),
+                path: "bahaullah/call-divine-beloved",
+            },
+            Work::DaysOfRemembrance => WorkData {
+                abbreviation: "DOR",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(2016),
+                path: "bahaullah/days-remembrance",
+            },
+            Work::EpistleToTheSonOfTheWolf => WorkData {
+                abbreviation: "ESW",
+                author: Some(Author::Bahaullah),
+                translator: SHOGHI_EFFENDI,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1941),
+                path: "bahaullah/epistle-son-wolf",
+            },
+            Work::Gleanings => WorkData {
+                abbreviation: "GWB",
+                author: Some(Author::Bahaullah),
+                translator: SHOGHI_EFFENDI,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1935),
+                path: "bahaullah/gleanings-writings-bahaullah",
+            },
+            Work::HiddenWords => WorkData {
+                abbreviation: "HW",
+                author: Some(Author::Bahaullah),
+                translator: SHOGHI_EFFENDI,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: None,
+                path: "bahaullah/hidden-words",
+            },
+            Work::KitabiAqdas => WorkData {
+                abbreviation: "KA",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(1992),
+                path: "bahaullah/kitab-i-aqdas",
+            },
+            Work::KitabiIqan => WorkData {
+                abbreviation: "KI",
+                author: Some(Author::Bahaullah),
+                translator: SHOGHI_EFFENDI,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1931),
+                path: "bahaullah/kitab-i-iqan",
+            },
+            Work::PrayersAndMeditations => WorkData {
+                abbreviation: "PM",
+                author: Some(Author::Bahaullah),
+                translator: SHOGHI_EFFENDI,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1938),
+                path: "bahaullah/prayers-meditations",
+            },
+            Work::SummonsOfTheLordOfHosts => WorkData {
+                abbreviation: "SLH",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(2002),
+                path: "bahaullah/summons-lord-hosts",
+            },
+            Work::TabernacleOfUnity => WorkData {
+                abbreviation: "TU",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(2006),
+                path: "bahaullah/tabernacle-unity",
+            },
+            Work::TabletsOfBahaullah => WorkData {
+                abbreviation: "TB",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(1978),
+                path: "bahaullah/tablets-bahaullah",
+            },
+            Work::AdditionalTabletsAndExtractsBahaullah => WorkData {
+                abbreviation: "ATB",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: None,
+                path: "bahaullah/additional-tablets-extracts-from-tablets-revealed-bahaullah",
+            },
+
+            // ‘Abdu’l‑Bahá
+            Work::LightOfTheWorld => WorkData {
+                abbreviation: "LOW",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(2021),
+                path: "abdul-baha/light-of-the-world",
+            },
+            Work::MemorialsOfTheFaithful => WorkData {
+                abbreviation: "MF",
+                author: Some(Author::AbdulBaha),
+                translator: Some("Marzieh Gail"),
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1971),
+                path: "abdul-baha/memorials-faithful",
+            },
+            Work::ParisTalks => WorkData {
+                abbreviation: "PT",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: None,
+                year: Some(1912),
+                path: "abdul-baha/paris-talks",
+            },
+            Work::PromulgationOfUniversalPeace => WorkData {
+                abbreviation: "PUP",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1922),
+                path: "abdul-baha/promulgation-universal-peace",
+            },
+            Work::SecretOfDivineCivilization => WorkData {
+                abbreviation: "SDC",
+                author: Some(Author::AbdulBaha),
+                translator: Some("Marzieh Gail"),
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1957),
+                path: "abdul-baha/secret-divine-civilization",
+            },
+            Work::SelectionsFromTheWritingsOfAbdulBaha => WorkData {
+                abbreviation: "SWAB",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(1978),
+                path: "abdul-baha/selections-writings-abdul-baha",
+            },
+            Work::SomeAnsweredQuestions => WorkData {
+                abbreviation: "SAQ",
+                author: Some(Author::AbdulBaha),
+                translator: Some("Laura Clifford Barney"),
+                publisher: None,
+                year: Some(1908),
+                path: "abdul-baha/some-answered-questions",
+            },
+            Work::TabletToDrAugusteForel => WorkData {
+                abbreviation: "TAF",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: None,
+                year: None,
+                path: "abdul-baha/tablet-august-forel",
+            },
+            Work::TabletsOfTheDivinePlan => WorkData {
+                abbreviation: "TDP",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: None,
+                path: "abdul-baha/tablets-divine-plan",
+            },
+            Work::TabletsToTheHague => WorkData {
+                abbreviation: "TTH",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: None,
+                year: None,
+                path: "abdul-baha/tablets-hague-abdul-baha",
+            },
+            Work::ATravelersNarrative => WorkData {
+                abbreviation: "TN",
+                author: Some(Author::AbdulBaha),
+                translator: Some("Edward G. Browne"),
+                publisher: None,
+                year: Some(1891),
+                path: "abdul-baha/travelers-narrative",
+            },
+            Work::TwelveTableTalks => WorkData {
+                abbreviation: "TTT",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: Some(2018),
+                path: "abdul-baha/twelve-table-talks-abdul-baha",
+            },
+            Work::WillAndTestamentOfAbdulBaha => WorkData {
+                abbreviation: "WT",
+                author: Some(Author::AbdulBaha),
+                translator: SHOGHI_EFFENDI,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: Some(1944),
+                path: "abdul-baha/will-testament-abdul-baha",
+            },
+            Work::AdditionalTabletsExtractsAndTalksAbdulBaha => WorkData {
+                abbreviation: "ATA",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: None,
+                path: "abdul-baha/additional-tablets-extracts-talks",
+            },
+
+            // Prayers
+            Work::BahaiPrayers => WorkData {
+                abbreviation: "BP",
+                author: None,
+                translator: None,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: None,
+                path: "prayers/bahai-prayers",
+            },
+            Work::AdditionalPrayersBahaullah => WorkData {
+                abbreviation: "APB",
+                author: Some(Author::Bahaullah),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: None,
+                path: "prayers/additional-prayers-bahaullah",
+            },
+            Work::AdditionalPrayersAbdulBaha => WorkData {
+                abbreviation: "APA",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: None,
+                path: "prayers/additional-prayers-abdul-baha",
+            },
+            Work::TwentySixPrayersAbdulBaha => WorkData {
+                abbreviation: "TSP",
+                author: Some(Author::AbdulBaha),
+                translator: None,
+                publisher: BAHAI_WORLD_CENTRE,
+                year: None,
+                path: "prayers/twenty-six-prayers-abdul-baha",
+            },
+            Work::PrayersAndTabletsForChildren => WorkData {
+                abbreviation: "BPTC",
+                author: None,
+                translator: None,
+                publisher: BAHAI_PUBLISHING_TRUST,
+                year: None,
+                path: "prayers/bahai-prayers-tablets-children",
+            },
+        }
+    }
+}
+
+impl WorkInfo {
+    /// The registry entries for all Works, whether embedded or not.
+    pub fn all() -> Vec<WorkInfo> {
+        Work::iter().map(|work| work.info()).collect()
+    }
+}
+
+impl From<BookTitle> for Work {
+    fn from(title: BookTitle) -> Self {
+        match title {
+            BookTitle::SelectionsFromTheWritingsOfTheBab => Work::SelectionsFromTheWritingsOfTheBab,
+            BookTitle::CallOfTheDivineBeloved => Work::CallOfTheDivineBeloved,
+            BookTitle::DaysOfRemembrance => Work::DaysOfRemembrance,
+            BookTitle::EpistleToTheSonOfTheWolf => Work::EpistleToTheSonOfTheWolf,
+            BookTitle::KitabiAqdas => Work::KitabiAqdas,
+            BookTitle::KitabiIqan => Work::KitabiIqan,
+            BookTitle::SummonsOfTheLordOfHosts => Work::SummonsOfTheLordOfHosts,
+            BookTitle::TabernacleOfUnity => Work::TabernacleOfUnity,
+            BookTitle::TabletsOfBahaullah => Work::TabletsOfBahaullah,
+            BookTitle::LightOfTheWorld => Work::LightOfTheWorld,
+            BookTitle::MemorialsOfTheFaithful => Work::MemorialsOfTheFaithful,
+            BookTitle::ParisTalks => Work::ParisTalks,
+            BookTitle::PromulgationOfUniversalPeace => Work::PromulgationOfUniversalPeace,
+            BookTitle::SecretOfDivineCivilization => Work::SecretOfDivineCivilization,
+            BookTitle::SelectionsFromTheWritingsOfAbdulBaha => {
+                Work::SelectionsFromTheWritingsOfAbdulBaha
+            }
+            BookTitle::SomeAnsweredQuestions => Work::SomeAnsweredQuestions,
+            BookTitle::TabletToDrAugusteForel => Work::TabletToDrAugusteForel,
+            BookTitle::TabletsOfTheDivinePlan => Work::TabletsOfTheDivinePlan,
+            BookTitle::TabletsToTheHague => Work::TabletsToTheHague,
+            BookTitle::ATravelersNarrative => Work::ATravelersNarrative,
+            BookTitle::TwelveTableTalks => Work::TwelveTableTalks,
+            BookTitle::WillAndTestamentOfAbdulBaha => Work::WillAndTestamentOfAbdulBaha,
+        }
+    }
+}
+
+impl From<TabletSource> for Work {
+    fn from(source: TabletSource) -> Self {
+        match source {
+            TabletSource::AdditionalTabletsAndExtractsBahaullah => {
+                Work::AdditionalTabletsAndExtractsBahaullah
+            }
+            TabletSource::AdditionalTabletsExtractsAndTalksAbdulBaha => {
+                Work::AdditionalTabletsExtractsAndTalksAbdulBaha
+            }
+        }
+    }
+}
+
+impl From<PrayerSource> for Work {
+    fn from(source: PrayerSource) -> Self {
+        match source {
+            PrayerSource::BahaiPrayers => Work::BahaiPrayers,
+            PrayerSource::AdditionalPrayersBahaullah => Work::AdditionalPrayersBahaullah,
+            PrayerSource::AdditionalPrayersAbdulBaha => Work::AdditionalPrayersAbdulBaha,
+            PrayerSource::TwentySixPrayersAbdulBaha => Work::TwentySixPrayersAbdulBaha,
+            PrayerSource::PrayersAndTabletsForChildren => Work::PrayersAndTabletsForChildren,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abbreviations_unique() {
+        let mut abbreviations = Work::iter().map(|w| w.abbreviation()).collect::<Vec<_>>();
+        abbreviations.sort();
+        abbreviations.dedup();
+        assert_eq!(abbreviations.len(), Work::iter().count());
+        assert_eq!(Work::from_abbreviation("gwb"), Some(Work::Gleanings));
+    }
+
+    #[test]
+    fn test_registry() {
+        let all = WorkInfo::all();
+        assert_eq!(all.len(), Work::iter().count());
+        for info in &all {
+            assert_eq!(info.title, info.work.title());
+            assert!(!info.title.contains(['\'', '"']), "{}", info.title);
+            assert!(!info.title.contains("’l-"), "{}", info.title);
+            assert!(
+                info.url
+                    .starts_with("https://www.bahai.org/library/authoritative-texts/")
+            );
+            assert!(info.url.ends_with('/'));
+            assert_eq!(info.embedded, info.count > 0);
+        }
+
+        let gleanings = Work::Gleanings.info();
+        assert_eq!(gleanings.abbreviation, "GWB");
+        assert_eq!(gleanings.author, Some(Author::Bahaullah));
+        assert_eq!(gleanings.translator.as_deref(), Some("Shoghi Effendi"));
+        assert_eq!(gleanings.year, Some(1935));
+        assert_eq!(Work::BahaiPrayers.author(), None);
+        assert_eq!(
+            Work::AdditionalTabletsAndExtractsBahaullah.title(),
+            "Additional Tablets and Extracts from Tablets Revealed by Bahá’u’lláh"
+        );
+        assert_eq!(
+            Work::LightOfTheWorld.title(),
+            "Light of the World: Selected Tablets of ‘Abdu’l‑Bahá"
+        );
+
+        #[cfg(feature = "embed-all")]
+        {
+            use crate::{EmbedAllTrait as _, Writings};
+
+            assert_eq!(Work::Gleanings.count(), 716);
+            assert_eq!(
+                all.iter().map(|info| info.count).sum::<usize>(),
+                Writings::all().len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_from() {
+        // Each title and source is the Work of the same name, by the same Author.
+        for title in BookTitle::iter() {
+            let work = Work::from(title);
+            assert_eq!(format!("{title:?}"), format!("{work:?}"));
+            assert_eq!(title.title(), work.title());
+            assert_eq!(Some(title.author()), work.author());
+        }
+        for source in TabletSource::iter() {
+            let work = Work::from(source);
+            assert_eq!(format!("{source:?}"), format!("{work:?}"));
+            assert_eq!(source.title(), work.title());
+            assert_eq!(Some(source.author()), work.author());
+        }
+        for source in PrayerSource::iter() {
+            assert_eq!(format!("{source:?}"), format!("{:?}", Work::from(source)));
+        }
+    }
+}
@@ -3,7 +3,7 @@ use strum::{EnumDiscriminants, EnumIter};
 
 use crate::{
     Author, BookParagraph, CDBParagraph, GleaningsParagraph, HiddenWord, MeditationParagraph,
-    PrayerParagraph, TabletParagraph, WritingsTrait,
+    ParagraphStyle, PrayerParagraph, TabletParagraph, Work, WritingsTrait,
 };
 
 /// Allows enumeration of all Writings types in the crate.
@@ -12,7 +12,7 @@ use crate::{
 #[serde(rename_all = "camelCase")]
 #[strum_discriminants(
     name(WritingsType),
-    derive(EnumIter, Serialize, Deserialize),
+    derive(EnumIter, Hash, Serialize, Deserialize),
     serde(rename_all = "camelCase")
 )]
 #[cfg_attr(
@@ -38,6 +38,39 @@ pub enum Writings {
     Tablet(TabletParagraph),
 }
 
+macro_rules! impl_writings_conversions {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        $(
+            impl From<$ty> for Writings {
+                fn from(value: $ty) -> Self {
+                    Writings::$variant(value)
+                }
+            }
+
+            impl TryFrom<Writings> for $ty {
+                type Error = Writings;
+
+                fn try_from(value: Writings) -> Result<Self, Self::Error> {
+                    match value {
+                        Writings::$variant(it) => Ok(it),
+                        other => Err(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_writings_conversions!(
+    Book(BookParagraph),
+    CDB(CDBParagraph),
+    Gleaning(GleaningsParagraph),
+    HiddenWord(HiddenWord),
+    Prayer(PrayerParagraph),
+    Meditation(MeditationParagraph),
+    Tablet(TabletParagraph),
+);
+
 #[cfg(feature = "indicium")]
 impl indicium::simple::Indexable for Writings {
     fn strings(&self) -> Vec<String> {
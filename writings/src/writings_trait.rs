@@ -1,14 +1,24 @@
-use crate::{Author, WritingsType};
+use crate::{Author, ParagraphStyle, Sentence, Work, WritingsType};
 
 pub trait WritingsTrait<T: WritingsTrait<T>>:
     std::fmt::Debug + Sized + Clone + PartialEq + Eq
 {
     fn ty(&self) -> WritingsType;
     fn ref_id(&self) -> String;
+    /// The [`Work`] this record belongs to.
+    fn work(&self) -> Work;
     fn title(&self) -> String;
     fn subtitle(&self) -> Option<String>;
     fn author(&self) -> Author;
     fn number(&self) -> Option<u32>;
     fn paragraph(&self) -> u32;
     fn text(&self) -> String;
+    /// The style of the paragraph; [`ParagraphStyle::Text`] unless the Work distinguishes others.
+    fn style(&self) -> ParagraphStyle {
+        ParagraphStyle::Text
+    }
+    /// The sentences of the text, with addresses like `ref_id#s3` (see [`Sentence`]).
+    fn sentences(&self) -> Vec<Sentence> {
+        Sentence::all_of(&self.ref_id(), &self.text())
+    }
 }
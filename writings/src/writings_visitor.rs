@@ -2,7 +2,7 @@
 
 use scraper::{ElementRef, Selector};
 
-use crate::{Citation, WritingsTrait, scraper_ext::ElementExt as _};
+use crate::{Citation, Toc, WritingsTrait, scraper_ext::ElementExt as _};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VisitorAction {
@@ -55,6 +55,9 @@ pub trait WritingsVisitor: std::fmt::Debug + Send + Sync + Default {
 
     fn get_visited(&self) -> &[Self::Writings];
 
+    /// The table of contents of the visited Writings, reflecting the structure of the Work.
+    fn get_toc(&self) -> Toc;
+
     fn get_citation_texts(&self, body_element: &ElementRef) -> Vec<CitationText> {
         let mut ct = vec![];
         for citation_link in body_element.select(&Selector::parse(".jf").unwrap()) {
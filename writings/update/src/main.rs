@@ -1,11 +1,22 @@
-use std::{fs, path::Path};
+use std::{fs, io::Read as _, path::Path};
 
 use regex::Regex;
 use writings::{
     CDBVisitor, EmbedAllTrait, GleaningsVisitor, HiddenWordsVisitor, MeditationsVisitor,
-    PrayersVisitor, WritingsTrait, WritingsVisitor,
+    PrayersVisitor, WritingsTrait, WritingsVisitor, normalize,
+    search::{SearchEngine, annotate_quotes},
 };
 
+const USAGE: &str = "\
+Usage: writings-update                    download and check the Writings
+       writings-update search-index [PATH] save the search analysis (default: search-index.json)
+       writings-update annotate [--json] PATH
+                                          annotate the quotations of the Writings in a Markdown
+                                          or plain text document (- for stdin), or report them
+                                          as JSON
+       writings-update lint               check the embedded Writings for text not in NFC and
+                                          inconsistent apostrophes and hyphens";
+
 async fn download<V: WritingsVisitor>(name: &str)
 where
     V::Writings: WritingsTrait<V::Writings> + EmbedAllTrait<V::Writings> + std::fmt::Debug,
@@ -80,8 +91,79 @@ where
         .unwrap_or_else(|err| panic!("Failed to write HTML file: {path:?} - {err:?}"));
 }
 
+/// Index the embedded Writings and save the index, for the API to load on start.
+fn save_search_index(path: &str) {
+    println!("Indexing Writings ...");
+    let engine = SearchEngine::default();
+    engine
+        .save(path)
+        .unwrap_or_else(|err| panic!("Failed to save search index: {path} - {err:?}"));
+    println!("Saved search index to {path}");
+}
+
+/// Print the document with a note on the source of each quotation, or the JSON report.
+fn annotate(path: &str, json: bool) {
+    let document = match path {
+        "-" => {
+            let mut document = String::new();
+            std::io::stdin()
+                .read_to_string(&mut document)
+                .unwrap_or_else(|err| panic!("Failed to read stdin - {err:?}"));
+            document
+        }
+        _ => fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("Failed to read document: {path} - {err:?}")),
+    };
+    let report = annotate_quotes(&document);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        return;
+    }
+    let markdown = matches!(
+        Path::new(path).extension().and_then(|e| e.to_str()),
+        Some("md" | "markdown")
+    );
+    print!("{}", report.annotated(&document, markdown));
+    eprintln!(
+        "{} quotations, {} near-matches",
+        report.quotations.len(),
+        report.near_matches.len()
+    );
+}
+
+/// Print the inconsistencies in the embedded Writings, and fail if there are any.
+fn lint() {
+    let issues = normalize::lint();
+    for issue in &issues {
+        println!("{issue}");
+    }
+    if !issues.is_empty() {
+        eprintln!("{} issues", issues.len());
+        std::process::exit(1);
+    }
+}
+
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] => {}
+        ["search-index"] => return save_search_index("search-index.json"),
+        ["search-index", path] => return save_search_index(path),
+        ["annotate", path] => return annotate(path, false),
+        ["annotate", "--json", path] => return annotate(path, true),
+        ["lint"] => return lint(),
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+
     download::<CDBVisitor>("call_divine_beloved").await;
     download::<PrayersVisitor>("prayers").await;
     download::<HiddenWordsVisitor>("hidden_words").await;